@@ -0,0 +1,150 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How long to keep sampling a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Number of timed runs.
+    pub runs: u32,
+    /// Stop sampling early once this much time has been spent, so slow days
+    /// do not take minutes. At least `MIN_RUNS` samples are always taken.
+    pub max_time: Duration,
+}
+
+impl Options {
+    pub const MIN_RUNS: u32 = 3;
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 25,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Times `f` according to `options` and returns one duration per run.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(options.runs as usize);
+    let start = Instant::now();
+    for run in 0..options.runs {
+        if run >= Options::MIN_RUNS && start.elapsed() >= options.max_time {
+            break;
+        }
+        let now = Instant::now();
+        black_box(f());
+        samples.push(now.elapsed());
+    }
+    samples
+}
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        // Sample variance is 20/3 ms².
+        assert_eq!(stats.stddev.as_micros(), 2581);
+
+        let single = Stats::from_samples(&[ms(3)]).unwrap();
+        assert_eq!(single.median, ms(3));
+        assert_eq!(single.stddev, Duration::ZERO);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let options = Options {
+            warmup: 2,
+            runs: 5,
+            max_time: Duration::from_secs(60),
+        };
+        let samples = measure(&options, || calls += 1);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(calls, 7);
+
+        let options = Options {
+            warmup: 0,
+            runs: 100,
+            max_time: Duration::ZERO,
+        };
+        assert_eq!(measure(&options, || ()).len(), Options::MIN_RUNS as usize);
+    }
+}
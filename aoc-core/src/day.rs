@@ -0,0 +1,31 @@
+use crate::{Answer, ParseError, Solution};
+
+/// Everything the runner needs to know about a single day.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    /// Path to the bundled puzzle input, used when no other input is given.
+    pub input_path: &'static str,
+    pub solution: &'static dyn Solution,
+}
+
+impl Day {
+    /// How many parts the puzzle has, which is 2 unless it has no part 2.
+    pub fn parts(&self) -> u8 {
+        1 + u8::from(self.solution.part2().is_some())
+    }
+
+    pub fn parser(&self) -> Option<fn(&str)> {
+        self.solution.parser()
+    }
+
+    /// Solves the given part, or returns `None` if the puzzle has no such part.
+    pub fn solve(&self, part: u8, input: &str) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(self.solution.part1(input)),
+            2 => self.solution.part2().map(|part2| part2(input)),
+            _ => None,
+        }
+    }
+}
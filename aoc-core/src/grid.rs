@@ -0,0 +1,334 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::Point,
+    parse::{Found, ParseError},
+};
+
+/// A rectangular grid of tiles stored row by row in a single `Vec`. Positions
+/// are `Point`s with `x` growing to the right and `y` growing down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every tile set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one tile per character with `tile`. Characters it rejects are
+    /// reported as not being `expected`, as are rows that are not as wide as
+    /// the first one and empty input.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                if width == Some(row_width) {
+                    return Err(row_error(input, &line[i..], row_width));
+                }
+                let Some(t) = tile(c) else {
+                    let mut err = ParseError::new(input, &line[i..], expected);
+                    err.found = Found::Token(c.to_string());
+                    return Err(err);
+                };
+                cells.push(t);
+                row_width += 1;
+            }
+
+            match width {
+                None if row_width == 0 => {
+                    return Err(ParseError::new(input, line, "a row of tiles"))
+                }
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(row_error(input, &line[line.len()..], width));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(ParseError::end(input, "a row of tiles"));
+        };
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.in_bounds(self.width, self.height)
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    /// The tile at `p`, or `None` outside the grid.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    /// Every tile along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (self.point_of(i), t))
+    }
+
+    /// The position of the first tile, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_of(i))
+    }
+
+    /// The up to four orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The up to eight neighbours of `p`, including diagonals, that are
+    /// inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|&d| d != Point::new(0, 0))
+            .map(move |d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width)
+    }
+
+    /// The tiles in column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height as i32;
+        self.remap(self.height, self.width, |p| {
+            Point::new(height - 1 - p.y, p.x)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width as i32;
+        self.remap(self.height, self.width, |p| {
+            Point::new(p.y, width - 1 - p.x)
+        })
+    }
+
+    /// A `width` by `height` grid where the tile at `p` moves to `to(p)`.
+    fn remap(&self, width: usize, height: usize, to: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells: Vec<Option<T>> = vec![None; self.cells.len()];
+        for (p, t) in self.iter() {
+            let q = to(p);
+            cells[q.y as usize * width + q.x as usize] = Some(t.clone());
+        }
+        Grid {
+            width,
+            height,
+            cells: cells.into_iter().map(Option::unwrap).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid whose tiles are the characters in `tiles`.
+    pub fn parse_tiles(input: &str, tiles: &str) -> Result<Self, ParseError> {
+        let tile_list: Vec<_> = tiles.chars().map(|c| format!("`{}`", c)).collect();
+        let expected = format!("one of {}", tile_list.join(" "));
+        Self::parse(input, &expected, |c| tiles.contains(c).then_some(c))
+    }
+}
+
+fn row_error(input: &str, at: &str, width: usize) -> ParseError {
+    ParseError::new(input, at, format!("a row of {} tiles", width))
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for t in row {
+                t.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.O\n..#\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_tiles(INPUT, "#.O").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 'O');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let bits = Grid::parse(INPUT, "a tile", |c| Some(c == '#')).unwrap();
+        assert_eq!(bits.row(1), &[false, false, true]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse_tiles("#.\n.x\n", "#.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected one of `#` `.`, found `x`"
+        );
+
+        let err = Grid::parse_tiles("#..\n.#\n", "#.").unwrap_err();
+        assert_eq!((err.line, err.column, err.found), (2, 3, Found::EndOfLine));
+        assert_eq!(err.expected, "a row of 3 tiles");
+
+        let err = Grid::parse_tiles("#.\n.##\n", "#.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Grid::parse_tiles("", "#.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a row of tiles, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0);
+        let corner: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner.len(), 2);
+        assert!(corner.contains(&Point::new(1, 0)) && corner.contains(&Point::new(0, 1)));
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse_tiles("abc\ndef\n", "abcdef").unwrap();
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.points().last(), Some(Point::new(2, 1)));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = Grid::parse_tiles("abc\ndef\n", "abcdef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
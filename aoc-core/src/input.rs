@@ -0,0 +1,125 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Day;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Input {
+    /// The input file checked in next to the day's crate.
+    #[default]
+    Bundled,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, InputError> {
+        match self {
+            Input::Bundled => read_file(Path::new(day.input_path)),
+            Input::File(path) => read_file(path),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Bundled => write!(f, "bundled input"),
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::File {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::File { path, source } => {
+                write!(f, "cannot read input file {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "cannot read input from stdin: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::File { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, Solution};
+
+    struct Length;
+
+    impl Solution for Length {
+        fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+            Ok(input.len().into())
+        }
+    }
+
+    const DAY: Day = Day {
+        number: 1,
+        title: "Test",
+        input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+        solution: &Length,
+    };
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Input::from_arg("-"), Input::Stdin);
+        assert_eq!(
+            Input::from_arg("day1/example.txt"),
+            Input::File(PathBuf::from("day1/example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        assert!(Input::Bundled.read(&DAY).unwrap().contains("aoc-core"));
+
+        let err = Input::from_arg("does/not/exist.txt")
+            .read(&DAY)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("cannot read input file does/not/exist.txt: "));
+    }
+}
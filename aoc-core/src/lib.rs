@@ -0,0 +1,15 @@
+//! Shared helpers for the Advent of Code 2023 solutions.
+
+pub mod bench;
+mod day;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+mod solution;
+
+pub use day::Day;
+pub use input::{Input, InputError};
+pub use parse::ParseError;
+pub use solution::{Answer, Part, Solution};
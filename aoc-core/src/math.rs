@@ -0,0 +1,315 @@
+//! Number theory for combining cycles. Everything works on `u64` and `u128`,
+//! and anything that could overflow returns `None` instead of wrapping.
+
+use std::{
+    fmt,
+    ops::{Add, BitAnd, Div, Mul, Rem, Shr, Sub},
+};
+
+/// The unsigned integer types the helpers in this module accept.
+pub trait Unsigned:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shr<u32, Output = Self>
+    + BitAnd<Output = Self>
+{
+    /// The signed type of the same width, used for Bézout coefficients.
+    type Signed: Copy + Ord + fmt::Debug;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// `self * rhs % m` without overflowing.
+    fn mul_mod(self, rhs: Self, m: Self) -> Self;
+
+    /// The signed value with the given magnitude, which must fit.
+    fn signed(magnitude: Self, negative: bool) -> Self::Signed;
+}
+
+macro_rules! impl_unsigned {
+    ($t:ty, $signed:ty, $mul_mod:expr) => {
+        impl Unsigned for $t {
+            type Signed = $signed;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn mul_mod(self, rhs: Self, m: Self) -> Self {
+                $mul_mod(self, rhs, m)
+            }
+
+            fn signed(magnitude: Self, negative: bool) -> $signed {
+                let n = <$signed>::try_from(magnitude).expect("magnitude out of range");
+                if negative {
+                    -n
+                } else {
+                    n
+                }
+            }
+        }
+    };
+}
+
+impl_unsigned!(u64, i64, |a: u64, b: u64, m: u64| {
+    (a as u128 * b as u128 % m as u128) as u64
+});
+impl_unsigned!(u128, i128, mul_mod_by_doubling);
+
+/// `a * b % m` by double-and-add, for when there is no wider type to multiply in.
+fn mul_mod_by_doubling(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+    let (mut a, mut b) = (a % m, b % m);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// `(a + b) % m` for `a` and `b` already below `m`.
+fn add_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Greatest common divisor using Euclid's algorithm.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it does not fit. Divides before
+/// multiplying so only a result that really overflows is rejected.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The gcd of `a` and `b` along with `x` and `y` such that `a*x + b*y = gcd`.
+/// The coefficients are the minimal ones Euclid's algorithm finds, so they
+/// always fit in the signed type of the same width.
+pub fn extended_gcd<T: Unsigned>(a: T, b: T) -> (T, T::Signed, T::Signed) {
+    let (g, x, y, odd) = bezout(a, b);
+    (
+        g,
+        T::signed(x, odd && x != T::ZERO),
+        T::signed(y, !odd && y != T::ZERO),
+    )
+}
+
+/// Extended Euclid on magnitudes only, which keeps every intermediate within
+/// `T`. The coefficients alternate in sign at every step: `x` is negative
+/// after an odd number of steps and `y` after an even number, so only the
+/// parity is returned alongside them.
+fn bezout<T: Unsigned>(a: T, b: T) -> (T, T, T, bool) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    let mut odd = false;
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 % r1);
+        (x0, x1) = (x1, x0 + q * x1);
+        (y0, y1) = (y1, y0 + q * y1);
+        odd = !odd;
+    }
+    (r0, x0, y0, odd)
+}
+
+/// The inverse of `a` modulo `m`, or `None` if they share a factor or `m` is
+/// zero.
+pub fn mod_inverse<T: Unsigned>(a: T, m: T) -> Option<T> {
+    if m == T::ZERO {
+        return None;
+    }
+    let (g, x, _, odd) = bezout(a % m, m);
+    if g != T::ONE {
+        return None;
+    }
+    let x = x % m;
+    Some(if odd && x != T::ZERO { m - x } else { x })
+}
+
+/// `base` to the power of `exp` modulo `m`. Panics if `m` is zero.
+pub fn modpow<T: Unsigned>(base: T, mut exp: T, m: T) -> T {
+    assert!(m != T::ZERO, "modpow with a zero modulus");
+    let mut base = base % m;
+    let mut result = T::ONE % m;
+    while exp != T::ZERO {
+        if exp & T::ONE == T::ONE {
+            result = result.mul_mod(base, m);
+        }
+        base = base.mul_mod(base, m);
+        exp = exp >> 1;
+    }
+    result
+}
+
+/// The largest integer whose square is at most `n`.
+pub fn isqrt<T: Unsigned>(n: T) -> T {
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return n;
+    }
+    // Newton's method from above; `n / 2 + 1` is past the root for n >= 2.
+    let mut x = n / two + T::ONE;
+    let mut y = (x + n / x) / two;
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+    x
+}
+
+/// Combines congruences `x ≡ residue (mod modulus)` into one, returning the
+/// smallest solution and the combined modulus (the lcm of all of them). The
+/// moduli need not be coprime. `None` if the congruences contradict each
+/// other, a modulus is zero, or the combined modulus overflows.
+pub fn crt<T: Unsigned>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            if m2 == T::ZERO {
+                return None;
+            }
+            let r2 = r2 % m2;
+            let g = gcd(m1, m2);
+            let (low, high) = (r1.min(r2), r1.max(r2));
+            if (high - low) % g != T::ZERO {
+                return None;
+            }
+
+            // Solve m1*k ≡ r2 - r1 (mod m2) for k, working modulo m2 / g.
+            let n = m2 / g;
+            let step = (high - low) / g % n;
+            let diff = if r2 >= r1 || step == T::ZERO {
+                step
+            } else {
+                n - step
+            };
+            let inverse = mod_inverse(m1 / g % n, n)?;
+            let k = diff.mul_mod(inverse, n);
+
+            let modulus = (m1 / g).checked_mul(m2)?;
+            // k < m2 / g, so this stays below the combined modulus.
+            Some((r1 + m1 * k, modulus))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(18u64, 12), 6);
+        assert_eq!(gcd(7u64, 0), 7);
+        assert_eq!(gcd(17u128, 5), 1);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!([2u64, 3, 4].into_iter().try_fold(1, lcm), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm(u64::MAX as u128, 2), Some(2 * u64::MAX as u128));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240u64, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(46u64, 240), (2, 47, -9));
+        assert_eq!(extended_gcd(5u64, 0), (5, 1, 0));
+        assert_eq!(extended_gcd(0u64, 5), (5, 0, 1));
+        for (a, b) in [(u64::MAX, u64::MAX - 1), (u64::MAX - 1, 3), (1 << 63, 1)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+        let (g, x, y) = extended_gcd(u128::MAX, u128::MAX - 1);
+        assert_eq!((g, x, y), (1, 1, -1));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3u64, 11), Some(4));
+        assert_eq!(mod_inverse(10u64, 17), Some(12));
+        assert_eq!(mod_inverse(6u64, 9), None);
+        assert_eq!(mod_inverse(3u64, 0), None);
+        assert_eq!(mod_inverse(0u64, 1), Some(0));
+        assert_eq!(mod_inverse(u128::MAX - 1, u128::MAX), Some(u128::MAX - 1));
+    }
+
+    #[test]
+    fn test_modpow() {
+        assert_eq!(modpow(2u64, 10, 1000), 24);
+        assert_eq!(modpow(3u64, 0, 7), 1);
+        assert_eq!(modpow(3u64, 5, 1), 0);
+        assert_eq!(modpow(u64::MAX - 1, 2, u64::MAX), 1);
+        // Fermat: a^(p-1) ≡ 1 for the prime 2^127 - 1.
+        let p = (1u128 << 127) - 1;
+        assert_eq!(modpow(123_456_789u128, p - 1, p), 1);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0u64..1000 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({})", n);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli that share factors.
+        assert_eq!(crt([(3u64, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(0u64, 6), (0, 10), (0, 15)]), Some((0, 30)));
+        assert_eq!(crt([(1u64, 4), (2, 6)]), None);
+        assert_eq!(crt([(1u64, 0)]), None);
+        assert_eq!(crt::<u64>([]), Some((0, 1)));
+        assert_eq!(crt([(1u64, 1 << 40), (0, 3 << 30)]), None);
+        assert_eq!(
+            crt([(1u64 << 40, 1 << 41), (1, 3u64.pow(30))]).map(|(_, m)| m),
+            None
+        );
+        let (x, m) = crt([(5u128, u64::MAX as u128), (7, u64::MAX as u128 - 2)]).unwrap();
+        assert_eq!((x % u64::MAX as u128, x % (u64::MAX as u128 - 2)), (5, 7));
+        assert_eq!(m, u64::MAX as u128 * (u64::MAX as u128 - 2));
+    }
+}
@@ -0,0 +1,248 @@
+use std::{fmt, str::FromStr};
+
+/// Where a puzzle input stopped making sense.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// What the parser was looking for, e.g. "a number" or "`:`".
+    pub expected: String,
+    pub found: Found,
+}
+
+/// What a parser found instead of the token it expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+    Token(String),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl ParseError {
+    /// An error at the start of `at`, which must be a slice of `src`. The
+    /// position is relative to the start of `src`.
+    pub fn new(src: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(src.as_ptr() as usize);
+        debug_assert!(offset <= src.len(), "`at` is not a slice of `src`");
+        let before = src.get(..offset).unwrap_or(src);
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        let rest = &src[before.len()..];
+        let token = rest
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        let found = if !token.is_empty() {
+            Found::Token(token.to_string())
+        } else if rest.is_empty() {
+            Found::EndOfInput
+        } else if rest.starts_with(['\n', '\r']) {
+            Found::EndOfLine
+        } else {
+            Found::Token(rest.chars().next().unwrap().to_string())
+        };
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// An error at the end of `src`, for input that stops too early.
+    pub fn end(src: &str, expected: impl Into<String>) -> Self {
+        Self::new(src, &src[src.len()..], expected)
+    }
+
+    /// Moves an error whose position is relative to `slice` to the position
+    /// of `slice` in `src`. Running off the end of `slice` is reported as
+    /// whatever follows it in `src`, usually the end of a line.
+    pub fn within(mut self, src: &str, slice: &str) -> Self {
+        let start = ParseError::new(src, slice, "");
+        if self.line == 1 {
+            self.column += start.column - 1;
+        }
+        self.line += start.line - 1;
+        if self.found == Found::EndOfInput {
+            self.found = ParseError::new(src, &slice[slice.len()..], "").found;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        match &self.found {
+            Found::Token(token) => write!(f, "`{}`", token),
+            Found::EndOfLine => write!(f, "end of line"),
+            Found::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses each line of `input` with `f`. Errors from `f` are relative to the
+/// line it was given and get moved to the line's position in `input`.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| f(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+/// Parses `token`, a slice of `src`, as a number.
+pub fn number<T: FromStr>(src: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        let mut err = ParseError::new(src, token, "a number");
+        if !token.is_empty() {
+            err.found = Found::Token(token.to_string());
+        }
+        err
+    })
+}
+
+/// Parses the whitespace separated numbers in `s`, a slice of `src`.
+pub fn number_list<T: FromStr>(src: &str, s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace()
+        .map(|token| number(src, token))
+        .collect()
+}
+
+/// Like `str::split_once`, but reports a missing `delimiter` at the end of `s`.
+pub fn split_once<'a>(
+    src: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter).ok_or_else(|| {
+        let expected = format!("`{}`", delimiter.escape_debug());
+        ParseError::new(src, &s[s.len()..], expected)
+    })
+}
+
+/// Like `str::strip_prefix`, but reports a missing `prefix` at the start of `s`.
+pub fn strip_prefix<'a>(src: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(src, s, format!("`{}`", prefix)))
+}
+
+/// Every integer in `s`, in order. A `-` directly in front of a digit is treated as a sign.
+pub fn numbers<T: FromStr>(s: &str) -> Vec<T>
+where
+    T::Err: std::fmt::Debug,
+{
+    let bytes = s.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        result.push(s[start..i].parse().unwrap());
+    }
+    result
+}
+
+/// Splits the input into blank-line separated blocks.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<u32>("Card 1: 41 48 | 83"), vec![1, 41, 48, 83]);
+        assert_eq!(numbers::<i64>("0 -3 6-9"), vec![0, -3, 6, -9]);
+        assert_eq!(numbers::<u8>("no numbers - here"), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Time: 7 15\nDistance: 9 x0\n";
+        let (_, distances) = input.split_once("Distance:").unwrap();
+        let err = number_list::<u32>(input, distances).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: expected a number, found `x0`"
+        );
+
+        let err = split_once("Time 7", "Time 7", ":").unwrap_err();
+        assert_eq!(err.found, Found::EndOfInput);
+
+        let line = input.lines().next().unwrap();
+        let err = split_once(input, line, ";").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 11: expected `;`, found end of line"
+        );
+
+        let err = strip_prefix(input, line, "Tim:").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found),
+            (1, 1, Found::Token("Time:".into()))
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "abc\nde fg\nhi\n";
+        let slice = &input[7..];
+        let err = ParseError::new(slice, &slice[1..], "x").within(input, slice);
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = ParseError::new(slice, &slice[4..], "x").within(input, slice);
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "1 2\n3 4\n5 y\n";
+        let err = lines(input, |line| number_list::<u8>(line, line)).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(
+            lines("1\n2", |line| number::<u8>(line, line)),
+            Ok(vec![1, 2])
+        );
+
+        let input = "Card 1: x\nCard 2: 1 | 2";
+        let err = lines(input, |line| split_once(line, line, " | ")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 10: expected ` | `, found end of line"
+        );
+        let err = lines("1 | 2\n3", |line| split_once(line, line, " | ")).unwrap_err();
+        assert_eq!((err.line, err.found), (2, Found::EndOfInput));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\nc\n\n\nd\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// Integers that do not fit in an `i64`.
+    BigInt(i128),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => n.fmt(f),
+            Answer::BigInt(n) => n.fmt(f),
+            Answer::Str(s) => f.pad(s),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::BigInt(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// A `Solution::parser` hook that runs `$parse` on the input and throws the
+/// result away, e.g. `parser!(Record::parse)`. `$parse` may also be a closure
+/// that captures nothing.
+#[macro_export]
+macro_rules! parser {
+    ($parse:expr) => {
+        Some(|input| {
+            let _ = std::hint::black_box(($parse)(input));
+        })
+    };
+}
+
+/// Solves one part of a puzzle from the raw input.
+pub type Part = fn(&str) -> Result<Answer, ParseError>;
+
+/// A puzzle solver. Both parts take the raw puzzle input and fail if it
+/// cannot be parsed or has no answer.
+pub trait Solution: Sync {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// The second part's solver, which says how many parts the puzzle has.
+    /// `None` for a puzzle with a single part, as only the last day of the
+    /// calendar is.
+    fn part2(&self) -> Option<Part> {
+        None
+    }
+
+    /// The day's input parser on its own, so benchmarks can report parse and
+    /// solve time separately. `None` for days that parse while solving.
+    fn parser(&self) -> Option<fn(&str)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42u32), Answer::Int(42));
+        assert_eq!(Answer::from(-7i64), Answer::Int(-7));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(1i128 << 100), Answer::BigInt(1 << 100));
+        assert_eq!(Answer::from("EZPZ"), Answer::Str("EZPZ".to_string()));
+    }
+
+    #[test]
+    fn test_single_part() {
+        struct Last;
+        impl Solution for Last {
+            fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+                Ok(input.len().into())
+            }
+        }
+
+        let day = crate::Day {
+            number: 25,
+            title: "Last",
+            input_path: "",
+            solution: &Last,
+        };
+        assert_eq!(day.solve(1, "abc"), Some(Ok(Answer::Int(3))));
+        assert_eq!(day.parts(), 1);
+        assert_eq!(day.solve(2, "abc"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Int(-3).to_string(), "-3");
+        assert_eq!(
+            Answer::BigInt(1 << 70).to_string(),
+            "1180591620717411303424"
+        );
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
@@ -0,0 +1,130 @@
+use std::{fs, path::PathBuf};
+
+/// The answer file checked in at the root of the workspace.
+pub const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// A recorded answer for one part of one day, run against `file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    /// Input file name, relative to the day's crate directory.
+    pub file: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl Expected {
+    /// Full path of the input file, resolved next to the day's bundled input.
+    pub fn input_path(&self, input_path: &str) -> PathBuf {
+        PathBuf::from(input_path)
+            .parent()
+            .map(|dir| dir.join(&self.file))
+            .unwrap_or_else(|| PathBuf::from(&self.file))
+    }
+}
+
+pub fn load() -> Result<Vec<Expected>, String> {
+    let contents = fs::read_to_string(ANSWERS_PATH)
+        .map_err(|err| format!("cannot read {}: {}", ANSWERS_PATH, err))?;
+    parse(&contents)
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Expected>, String> {
+    let table = contents
+        .parse::<toml::Table>()
+        .map_err(|err| format!("invalid answer file: {}", err))?;
+
+    let mut expected = Vec::new();
+    for (day, files) in table {
+        let day = day
+            .parse::<u8>()
+            .map_err(|_| format!("invalid day: {}", day))?;
+        let files = files
+            .as_table()
+            .ok_or_else(|| format!("day {}: expected a table of input files", day))?;
+
+        for (file, parts) in files {
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("day {} {}: expected a table of parts", day, file))?;
+
+            for (part, answer) in parts {
+                let part = match part.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("day {} {}: unknown part {}", day, file, part)),
+                };
+                let answer = match answer {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => {
+                        return Err(format!(
+                            "day {} {} part {}: answers must be integers or strings",
+                            day, file, part
+                        ))
+                    }
+                };
+
+                expected.push(Expected {
+                    day,
+                    file: file.clone(),
+                    part,
+                    answer,
+                });
+            }
+        }
+    }
+
+    expected.sort_by(|a, b| (a.day, &a.file, a.part).cmp(&(b.day, &b.file, b.part)));
+    Ok(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = parse(
+            r#"
+            [3]
+            "input.txt" = { part2 = 7, part1 = "abc" }
+            [1]
+            "example.txt" = { part1 = 142 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                Expected {
+                    day: 1,
+                    file: "example.txt".to_string(),
+                    part: 1,
+                    answer: "142".to_string()
+                },
+                Expected {
+                    day: 3,
+                    file: "input.txt".to_string(),
+                    part: 1,
+                    answer: "abc".to_string()
+                },
+                Expected {
+                    day: 3,
+                    file: "input.txt".to_string(),
+                    part: 2,
+                    answer: "7".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("[x]").is_err());
+        assert!(parse("[1]\n\"input.txt\" = 3").is_err());
+        assert!(parse("[1]\n\"input.txt\" = { part3 = 1 }").is_err());
+        assert!(parse("[1]\n\"input.txt\" = { part1 = 1.5 }").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use aoc_core::{bench, Input};
+
+pub const USAGE: &str = "Usage:
+    aoc list
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json>]
+    aoc bench <day|all> [--part <1|2>] [--input <path|->]
+              [--warmup <n>] [--runs <n>] [--max-time <seconds>]
+
+Without --input each day reads its bundled input file; `-` reads stdin.
+`--format json` prints every answer, timing and error as one JSON document.
+Benchmarks default to 3 warm-up runs and 25 timed runs, sampling each part
+for at most 5 seconds.";
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+/// How `run` prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List,
+    Run {
+        selection: Selection,
+        part: Option<u8>,
+        input: Input,
+        format: Format,
+    },
+    Bench {
+        selection: Selection,
+        part: Option<u8>,
+        input: Input,
+        options: bench::Options,
+    },
+}
+
+impl Command {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        match args.next().as_deref() {
+            Some("list") => match args.next() {
+                None => Ok(Command::List),
+                Some(arg) => Err(format!("unexpected argument: {}", arg)),
+            },
+            Some(command @ ("run" | "bench")) => {
+                let bench = command == "bench";
+                let selection = match args.next().as_deref() {
+                    Some("all") => Selection::All,
+                    Some(day) => Selection::Day(parse_day(day)?),
+                    None => return Err("missing day".to_string()),
+                };
+
+                let mut part = None;
+                let mut input = Input::Bundled;
+                let mut options = bench::Options::default();
+                let mut format = Format::default();
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+                    match arg.as_str() {
+                        "--part" | "-p" => part = Some(parse_part(&value()?)?),
+                        "--input" | "-i" => input = Input::from_arg(&value()?),
+                        "--format" if !bench => format = parse_format(&value()?)?,
+                        "--warmup" if bench => options.warmup = parse_count(&value()?, 0)?,
+                        "--runs" if bench => options.runs = parse_count(&value()?, 1)?,
+                        "--max-time" if bench => options.max_time = parse_seconds(&value()?)?,
+                        _ => return Err(format!("unexpected argument: {}", arg)),
+                    }
+                }
+
+                if selection == Selection::All && input != Input::Bundled {
+                    return Err("--input can only be used with a single day".to_string());
+                }
+
+                if bench {
+                    Ok(Command::Bench {
+                        selection,
+                        part,
+                        input,
+                        options,
+                    })
+                } else {
+                    Ok(Command::Run {
+                        selection,
+                        part,
+                        input,
+                        format,
+                    })
+                }
+            }
+            Some(command) => Err(format!("unknown command: {}", command)),
+            None => Err("missing command".to_string()),
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day: {} (expected 1-25 or all)", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("invalid part: {} (expected 1 or 2)", s)),
+    }
+}
+
+fn parse_format(s: &str) -> Result<Format, String> {
+    match s {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("invalid format: {} (expected text or json)", s)),
+    }
+}
+
+fn parse_count(s: &str, min: u32) -> Result<u32, String> {
+    match s.parse::<u32>() {
+        Ok(n) if n >= min => Ok(n),
+        _ => Err(format!("invalid count: {} (expected at least {})", s, min)),
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid number of seconds: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse("run 17 --part 2"),
+            Ok(Command::Run {
+                selection: Selection::Day(17),
+                part: Some(2),
+                input: Input::Bundled,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse("run all"),
+            Ok(Command::Run {
+                selection: Selection::All,
+                part: None,
+                input: Input::Bundled,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse("run 1 --input day1/example.txt"),
+            Ok(Command::Run {
+                selection: Selection::Day(1),
+                part: None,
+                input: Input::File("day1/example.txt".into()),
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse("run 1 -i -"),
+            Ok(Command::Run {
+                selection: Selection::Day(1),
+                part: None,
+                input: Input::Stdin,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse("run all --format json"),
+            Ok(Command::Run {
+                selection: Selection::All,
+                part: None,
+                input: Input::Bundled,
+                format: Format::Json,
+            })
+        );
+        assert_eq!(parse("list"), Ok(Command::List));
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse("bench 24 --part 2 --runs 10 --warmup 0 --max-time 1.5"),
+            Ok(Command::Bench {
+                selection: Selection::Day(24),
+                part: Some(2),
+                input: Input::Bundled,
+                options: bench::Options {
+                    warmup: 0,
+                    runs: 10,
+                    max_time: Duration::from_millis(1500),
+                },
+            })
+        );
+        assert_eq!(
+            parse("bench all"),
+            Ok(Command::Bench {
+                selection: Selection::All,
+                part: None,
+                input: Input::Bundled,
+                options: bench::Options::default(),
+            })
+        );
+        assert!(parse("bench 1 --runs 0").is_err());
+        assert!(parse("bench 1 --max-time -1").is_err());
+        assert!(parse("run 1 --runs 5").is_err());
+        assert!(parse("bench 1 --format json").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 1 --part 3").is_err());
+        assert!(parse("run 1 --part").is_err());
+        assert!(parse("run 1 --input").is_err());
+        assert!(parse("run 1 --format yaml").is_err());
+        assert!(parse("run all --input day1/example.txt").is_err());
+        assert!(parse("walk 1").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! The `aoc` runner: command line parsing, the table of days and the
+//! recorded answers they are checked against, and the JSON report of a run.
+
+pub mod answers;
+pub mod cli;
+pub mod days;
+pub mod report;
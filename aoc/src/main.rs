@@ -0,0 +1,213 @@
+use std::{process::ExitCode, time::Instant};
+
+use aoc::{
+    cli::{Command, Format, Selection, USAGE},
+    days,
+    report::{self, Outcome, PartReport},
+};
+use aoc_core::{
+    bench::{self, Stats},
+    Day, Input, ParseError,
+};
+
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::List => {
+            for day in &days::DAYS {
+                let parts = if day.parts() == 2 { "1, 2" } else { "1" };
+                println!("Day {:>2}  {:<32} parts: {}", day.number, day.title, parts);
+            }
+            Ok(())
+        }
+        Command::Run {
+            selection,
+            part,
+            input,
+            format: Format::Json,
+        } => run_json(selection, part, &input),
+        Command::Run {
+            selection,
+            part,
+            input,
+            format: Format::Text,
+        } => for_each_part(selection, part, &input, |day, part, input| {
+            let start = Instant::now();
+            let answer = day.solve(part, input).expect("part exists")?;
+            println!(
+                "Day {:>2} part {}: {:<20} ({:.2?})",
+                day.number,
+                part,
+                answer,
+                start.elapsed()
+            );
+            Ok(())
+        }),
+        Command::Bench {
+            selection,
+            part,
+            input,
+            options,
+        } => for_each_part(selection, part, &input, |day, part, input| {
+            benchmark(day, part, input, &options)
+        }),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The selected days, each with the parts to run.
+fn selected_parts(
+    selection: Selection,
+    part: Option<u8>,
+) -> Result<Vec<(&'static Day, Vec<u8>)>, String> {
+    let selected: Vec<&Day> = match selection {
+        Selection::All => days::DAYS.iter().collect(),
+        Selection::Day(number) => days::find(number).into_iter().collect(),
+    };
+
+    // Asking for a part a single day does not have is an error, while
+    // `run all` just skips it.
+    let explicit = part.is_some() && matches!(selection, Selection::Day(_));
+    let mut result = Vec::new();
+    for day in selected {
+        let parts = match part {
+            Some(part) if part > day.parts() && explicit => {
+                return Err(format!("day {} has no part {}", day.number, part));
+            }
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        let parts = parts
+            .into_iter()
+            .filter(|&part| part <= day.parts())
+            .collect();
+        result.push((day, parts));
+    }
+    Ok(result)
+}
+
+/// The path a day's input is read from, or `None` for stdin.
+fn input_path(day: &Day, input: &Input) -> Option<String> {
+    match input {
+        Input::Bundled => Some(day.input_path.to_string()),
+        Input::File(path) => Some(path.display().to_string()),
+        Input::Stdin => None,
+    }
+}
+
+/// Reads the input of every selected day once and calls `f` for each
+/// requested part.
+fn for_each_part(
+    selection: Selection,
+    part: Option<u8>,
+    input: &Input,
+    mut f: impl FnMut(&Day, u8, &str) -> Result<(), ParseError>,
+) -> Result<(), String> {
+    for (day, parts) in selected_parts(selection, part)? {
+        let input_name = input_path(day, input).unwrap_or_else(|| input.to_string());
+        let input = input
+            .read(day)
+            .map_err(|err| format!("day {}: {}", day.number, err))?;
+
+        for part in parts {
+            f(day, part, &input).map_err(|err| {
+                format!("day {} part {}: {}: {}", day.number, part, input_name, err)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs every selected part and prints the results as one JSON document.
+/// Unlike the text output, a failing part does not stop the others.
+fn run_json(selection: Selection, part: Option<u8>, input: &Input) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for (day, parts) in selected_parts(selection, part)? {
+        let contents = input.read(day);
+        let input_sha256 = contents.as_deref().ok().map(report::sha256_hex);
+        for part in parts {
+            let outcome = match &contents {
+                Ok(contents) => solve_timed(day, part, contents),
+                Err(err) => Outcome::Input(err.to_string()),
+            };
+            reports.push(PartReport {
+                day,
+                part,
+                input_path: input_path(day, input),
+                input_sha256: input_sha256.clone(),
+                outcome,
+            });
+        }
+    }
+
+    println!("{:#}", report::document(&reports));
+    match reports.iter().filter(|report| !report.is_ok()).count() {
+        0 => Ok(()),
+        failed => Err(format!("{} of {} parts failed", failed, reports.len())),
+    }
+}
+
+/// Solves one part once. Days that expose their parser have it timed
+/// separately, and the solve time is what remains of the total.
+fn solve_timed(day: &Day, part: u8, input: &str) -> Outcome {
+    let parse = day.parser().map(|parse| {
+        let start = Instant::now();
+        parse(input);
+        start.elapsed()
+    });
+
+    let start = Instant::now();
+    let result = day.solve(part, input).expect("part exists");
+    let total = start.elapsed();
+    match result {
+        Ok(answer) => Outcome::Solved {
+            answer,
+            parse,
+            solve: total.saturating_sub(parse.unwrap_or_default()),
+        },
+        Err(err) => Outcome::Parse(err),
+    }
+}
+
+/// Prints timing statistics for one part. Days that expose their parser also
+/// get the parse time measured on its own; the solve time is what remains.
+fn benchmark(day: &Day, part: u8, input: &str, options: &bench::Options) -> Result<(), ParseError> {
+    // Fail on malformed input before timing anything.
+    day.solve(part, input).expect("part exists")?;
+
+    let total = bench::measure(options, || day.solve(part, input));
+    let stats = Stats::from_samples(&total).expect("at least one run");
+    println!("Day {:>2} part {} ({} runs)", day.number, part, stats.runs);
+    println!("    total  {}", stats);
+
+    if let Some(parse) = day.parser() {
+        let parse = bench::measure(options, || parse(input));
+        let parse_stats = Stats::from_samples(&parse).expect("at least one run");
+        let solve: Vec<_> = total
+            .iter()
+            .map(|t| t.saturating_sub(parse_stats.mean))
+            .collect();
+        println!("    parse  {}", parse_stats);
+        println!(
+            "    solve  {}",
+            Stats::from_samples(&solve).expect("at least one run")
+        );
+    } else {
+        println!("    parse  not timed, this day parses while it solves");
+    }
+    Ok(())
+}
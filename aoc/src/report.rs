@@ -0,0 +1,182 @@
+//! The JSON document `aoc run --format json` prints, so scripts do not have to
+//! scrape the human-readable output.
+
+use std::time::Duration;
+
+use aoc_core::{parse::Found, Answer, Day, ParseError};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+/// What happened when one part was run.
+#[derive(Debug)]
+pub enum Outcome {
+    /// `parse` is only known for days whose parser can be timed on its own;
+    /// `solve` is the rest of the time the part took.
+    Solved {
+        answer: Answer,
+        parse: Option<Duration>,
+        solve: Duration,
+    },
+    Parse(ParseError),
+    /// The input could not be read at all.
+    Input(String),
+}
+
+/// The result of running one part of one day.
+pub struct PartReport<'a> {
+    pub day: &'a Day,
+    pub part: u8,
+    /// `None` for stdin.
+    pub input_path: Option<String>,
+    /// Hex SHA-256 of the input, or `None` if it could not be read.
+    pub input_sha256: Option<String>,
+    pub outcome: Outcome,
+}
+
+impl PartReport<'_> {
+    pub fn is_ok(&self) -> bool {
+        matches!(self.outcome, Outcome::Solved { .. })
+    }
+
+    pub fn to_json(&self) -> Value {
+        let (answer, parse, solve, error) = match &self.outcome {
+            Outcome::Solved {
+                answer,
+                parse,
+                solve,
+            } => (
+                // Answers are strings so that large integers survive parsers
+                // that read every number as a double.
+                Some(answer.to_string()),
+                parse.map(nanos),
+                Some(nanos(*solve)),
+                None,
+            ),
+            Outcome::Parse(err) => (None, None, None, Some(parse_error(err))),
+            Outcome::Input(message) => (
+                None,
+                None,
+                None,
+                Some(json!({ "kind": "input", "message": message })),
+            ),
+        };
+
+        json!({
+            "day": self.day.number,
+            "title": self.day.title,
+            "part": self.part,
+            "input": {
+                "path": self.input_path,
+                "sha256": self.input_sha256,
+            },
+            "answer": answer,
+            "parse_ns": parse,
+            "solve_ns": solve,
+            "error": error,
+        })
+    }
+}
+
+/// The whole document: every part that was run, in order.
+pub fn document(reports: &[PartReport]) -> Value {
+    json!({
+        "ok": reports.iter().all(PartReport::is_ok),
+        "results": reports.iter().map(PartReport::to_json).collect::<Vec<_>>(),
+    })
+}
+
+pub fn sha256_hex(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn parse_error(err: &ParseError) -> Value {
+    let found = match &err.found {
+        Found::Token(token) => json!({ "token": token }),
+        Found::EndOfLine => json!("end of line"),
+        Found::EndOfInput => json!("end of input"),
+    };
+    json!({
+        "kind": "parse",
+        "message": err.to_string(),
+        "line": err.line,
+        "column": err.column,
+        "expected": err.expected,
+        "found": found,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: &Day = &day1::DAY;
+
+    #[test]
+    fn test_solved() {
+        let report = PartReport {
+            day: DAY,
+            part: 2,
+            input_path: None,
+            input_sha256: Some(sha256_hex("")),
+            outcome: Outcome::Solved {
+                answer: Answer::Int(281),
+                parse: None,
+                solve: Duration::from_micros(15),
+            },
+        };
+        assert_eq!(
+            report.to_json(),
+            json!({
+                "day": 1,
+                "title": DAY.title,
+                "part": 2,
+                "input": {
+                    "path": null,
+                    "sha256": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                },
+                "answer": "281",
+                "parse_ns": null,
+                "solve_ns": 15000,
+                "error": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "1abc2\nnone\n";
+        let err = DAY.solve(1, input).unwrap().unwrap_err();
+        let reports = [
+            PartReport {
+                day: DAY,
+                part: 1,
+                input_path: Some("day1/bad.txt".to_string()),
+                input_sha256: Some(sha256_hex(input)),
+                outcome: Outcome::Parse(err),
+            },
+            PartReport {
+                day: DAY,
+                part: 1,
+                input_path: Some("missing.txt".to_string()),
+                input_sha256: None,
+                outcome: Outcome::Input("cannot read input file missing.txt".to_string()),
+            },
+        ];
+
+        let document = document(&reports);
+        assert_eq!(document["ok"], json!(false));
+        let error = &document["results"][0]["error"];
+        assert_eq!(error["kind"], json!("parse"));
+        assert_eq!((&error["line"], &error["column"]), (&json!(2), &json!(1)));
+        assert_eq!(error["found"], json!({ "token": "none" }));
+        assert_eq!(document["results"][0]["answer"], Value::Null);
+        assert_eq!(document["results"][1]["error"]["kind"], json!("input"));
+    }
+}
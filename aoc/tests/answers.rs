@@ -0,0 +1,126 @@
+//! Runs every recorded answer in `answers.toml` against its input.
+
+use aoc::{answers, days};
+use aoc_core::Input;
+
+fn check(number: u8) {
+    let day = days::find(number).unwrap();
+    let expected: Vec<_> = answers::load()
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.day == number)
+        .collect();
+    assert!(
+        !expected.is_empty(),
+        "day {} has no recorded answers",
+        number
+    );
+
+    let mut mismatches = Vec::new();
+    for e in &expected {
+        let input = Input::File(e.input_path(day.input_path)).read(day).unwrap();
+        let actual = day
+            .solve(e.part, &input)
+            .unwrap_or_else(|| panic!("day {} has no part {}", number, e.part));
+
+        let actual = match actual {
+            Ok(actual) => actual,
+            Err(err) => {
+                mismatches.push(format!("{} part {}: {}", e.file, e.part, err));
+                continue;
+            }
+        };
+        if actual.to_string() != e.answer {
+            mismatches.push(format!(
+                "{} part {}: expected {}, got {}",
+                e.file, e.part, e.answer, actual
+            ));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "day {}:\n{}",
+        number,
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn every_part_has_an_answer() {
+    let expected = answers::load().unwrap();
+    for day in &days::DAYS {
+        let bundled = std::path::Path::new(day.input_path)
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap();
+        for part in 1..=day.parts() {
+            assert!(
+                expected
+                    .iter()
+                    .any(|e| e.day == day.number && e.file == bundled && e.part == part),
+                "day {} part {} has no recorded answer for {}",
+                day.number,
+                part,
+                bundled
+            );
+        }
+    }
+}
+
+/// The puzzle inputs are each day's bundled input plus the files recorded in
+/// `answers.toml`; other `.txt` files in a day's directory, such as drawings
+/// copied from the puzzle text, are not checked.
+#[test]
+fn every_recorded_input_exists() {
+    for e in answers::load().unwrap() {
+        let day = days::find(e.day).unwrap();
+        let path = e.input_path(day.input_path);
+        assert!(
+            path.is_file(),
+            "day {} has an answer for {}, which does not exist",
+            e.day,
+            path.display()
+        );
+    }
+}
+
+macro_rules! answer_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}
@@ -0,0 +1,39 @@
+// Prints every line of a calibration document with the digits each part
+// reads from it marked, and the lines that have none.
+//
+//     cargo run --release -p day1 --example audit -- 2 day1/input1.txt
+
+use std::process::ExitCode;
+
+use aoc_core::Input;
+use day1::{Audit, Vocabulary, DAY};
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let vocabulary = match args.next().as_deref() {
+        Some("1") => None,
+        Some("2") | None => Some(Vocabulary::english()),
+        Some(part) => {
+            eprintln!("usage: audit [1|2] [input path|-] (not part {})", part);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = args
+        .next()
+        .map_or(Input::Bundled, |arg| Input::from_arg(&arg));
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let audit = Audit::new(&input, vocabulary.as_ref());
+    print!("{}", audit);
+    if audit.errors().next().is_some() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,54 @@
+// Writes a seeded adversarial calibration document to stdout and the sums
+// each part should find in it to stderr.
+//
+//     cargo run --release -p day1 --example generate -- 42 1000000 > huge.txt
+//     cargo run --release -p day1 --example generate -- 7 500 20000 german
+
+use std::{
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use day1::{GeneratorOptions, Vocabulary};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let usage = "usage: generate <seed> <lines> [long line length] [vocabulary]";
+    let (Some(seed), Some(lines)) = (
+        args.first().and_then(|s| s.parse().ok()),
+        args.get(1).and_then(|s| s.parse().ok()),
+    ) else {
+        eprintln!("{}", usage);
+        return ExitCode::FAILURE;
+    };
+    let mut options = GeneratorOptions {
+        lines,
+        ..GeneratorOptions::default()
+    };
+    if let Some(len) = args.get(2) {
+        match len.parse() {
+            Ok(len) => options.long_line_len = len,
+            Err(_) => {
+                eprintln!("{}", usage);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let name = args.get(3).map_or("english", String::as_str);
+    let Some(vocabulary) = Vocabulary::preset(name) else {
+        eprintln!("error: unknown vocabulary {}", name);
+        return ExitCode::FAILURE;
+    };
+
+    let document = day1::generate(seed, &options, &vocabulary);
+    if let Err(err) = io::stdout().lock().write_all(document.text.as_bytes()) {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    match document.part1 {
+        Some(sum) => eprintln!("part 1: {}", sum),
+        None => eprintln!("part 1: rejected, some lines have no numeral"),
+    }
+    eprintln!("part 2: {}", document.part2);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,82 @@
+// Sums a calibration document of any size without reading it into memory,
+// and reports how fast it got through it.
+//
+//     cargo run --release -p day1 --example stream -- huge.txt 2
+//     cat huge.txt | cargo run --release -p day1 --example stream -- - 1
+
+use std::{
+    fs::File,
+    io::{self, BufReader, Read},
+    process::ExitCode,
+    time::Instant,
+};
+
+use day1::{StreamError, Vocabulary};
+
+/// Counts the bytes consumed from a reader.
+struct Counted<R> {
+    inner: R,
+    bytes: u64,
+}
+
+impl<R: Read> Read for Counted<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.bytes += n as u64;
+        Ok(n)
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (Some(path), part) = (args.next(), args.next()) else {
+        eprintln!("usage: stream <path|-> [1|2]");
+        return ExitCode::FAILURE;
+    };
+    let vocabulary = match part.as_deref() {
+        Some("1") => None,
+        Some("2") | None => Some(Vocabulary::english()),
+        Some(part) => {
+            eprintln!("error: no part {}", part);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let inner: Box<dyn Read> = if path == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        match File::open(&path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("error: cannot open {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let mut reader = BufReader::with_capacity(1 << 20, Counted { inner, bytes: 0 });
+
+    let start = Instant::now();
+    let result = day1::sum_lines(&mut reader, vocabulary.as_ref());
+    let elapsed = start.elapsed();
+    match result {
+        Ok(sum) => {
+            let bytes = reader.get_ref().bytes;
+            println!("{}", sum);
+            eprintln!(
+                "{:.1} MB in {:.2?} ({:.2} GB/s)",
+                bytes as f64 / 1e6,
+                elapsed,
+                bytes as f64 / 1e9 / elapsed.as_secs_f64()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(StreamError::Parse(err)) => {
+            eprintln!("error: {}: {}", path, err);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
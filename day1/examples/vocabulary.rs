@@ -0,0 +1,63 @@
+// Runs part 2 with digits spelled out in another vocabulary, either one of
+// the presets or a file of `word = digit` lines.
+//
+//     cargo run --release -p day1 --example vocabulary -- german
+//     cargo run --release -p day1 --example vocabulary -- words.txt notes.txt
+
+use std::{fs, process::ExitCode};
+
+use aoc_core::Input;
+use day1::{Vocabulary, DAY};
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(name) = args.next() else {
+        let presets: Vec<_> = Vocabulary::preset_names().collect();
+        eprintln!(
+            "usage: vocabulary <{}|config file> [input path|-]",
+            presets.join("|")
+        );
+        return ExitCode::FAILURE;
+    };
+    let input = args
+        .next()
+        .map_or(Input::Bundled, |arg| Input::from_arg(&arg));
+
+    let vocabulary = match Vocabulary::preset(&name) {
+        Some(vocabulary) => vocabulary,
+        None => {
+            let config = match fs::read_to_string(&name) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!(
+                        "error: {} is not a preset or a readable file: {}",
+                        name, err
+                    );
+                    return ExitCode::FAILURE;
+                }
+            };
+            match Vocabulary::parse(&config) {
+                Ok(vocabulary) => vocabulary,
+                Err(err) => {
+                    eprintln!("error: {}: {}", name, err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let result = input
+        .read(&DAY)
+        .map_err(|err| err.to_string())
+        .and_then(|input| day1::part2_with(&input, &vocabulary).map_err(|err| err.to_string()));
+    match result {
+        Ok(sum) => {
+            println!("{}", sum);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+
+use aoc_core::ParseError;
+
+use crate::{first_and_last, Token, Vocabulary};
+
+/// What one line of a calibration document contributes to the sum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineAudit<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub line: &'a str,
+    /// The first and last digit, or why the line has no calibration value.
+    pub tokens: Result<(Token, Token), ParseError>,
+}
+
+impl LineAudit<'_> {
+    pub fn value(&self) -> Option<u32> {
+        let (first, last) = self.tokens.as_ref().ok()?;
+        Some(first.digit * 10 + last.digit)
+    }
+}
+
+/// Every line of a calibration document with the digits found in it. Unlike
+/// the parts, which stop at the first bad line, an audit keeps going and
+/// reports every line without a digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit<'a> {
+    pub lines: Vec<LineAudit<'a>>,
+}
+
+impl<'a> Audit<'a> {
+    /// Audits `input` the way part 1 reads it without a vocabulary, or the
+    /// way part 2 does with one.
+    pub fn new(input: &'a str, vocabulary: Option<&Vocabulary>) -> Self {
+        let lines = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| LineAudit {
+                number: i + 1,
+                line,
+                tokens: first_and_last(line, vocabulary)
+                    .ok_or_else(|| ParseError::new(input, line, "a digit")),
+            })
+            .collect();
+        Self { lines }
+    }
+
+    /// The sum of the lines that have a calibration value.
+    pub fn sum(&self) -> u32 {
+        self.lines.iter().filter_map(LineAudit::value).sum()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &ParseError> {
+        self.lines
+            .iter()
+            .filter_map(|line| line.tokens.as_ref().err())
+    }
+}
+
+/// An annotated listing: each line with its first and last digit marked
+/// underneath, followed by the sum.
+impl fmt::Display for Audit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.lines.len().to_string().len();
+        for audit in &self.lines {
+            writeln!(f, "{:>width$} | {}", audit.number, audit.line)?;
+            match &audit.tokens {
+                Ok((first, last)) => {
+                    let mut marks: Vec<char> = audit.line.chars().map(|_| ' ').collect();
+                    for token in [first, last] {
+                        let start = audit.line[..token.start].chars().count();
+                        let len = audit.line[token.start..token.end()].chars().count();
+                        marks[start..start + len].fill('^');
+                    }
+                    let marks: String = marks.into_iter().collect();
+                    write!(f, "{:>width$} | {} ", "", marks.trim_end())?;
+                    if first == last {
+                        writeln!(
+                            f,
+                            "{}{}: only {}",
+                            first.digit,
+                            last.digit,
+                            describe(audit.line, first)
+                        )?;
+                    } else {
+                        writeln!(
+                            f,
+                            "{}{}: first {}, last {}",
+                            first.digit,
+                            last.digit,
+                            describe(audit.line, first),
+                            describe(audit.line, last)
+                        )?;
+                    }
+                }
+                Err(err) => writeln!(f, "{:>width$} = error: {}", "", err)?,
+            }
+        }
+
+        let errors = self.errors().count();
+        write!(
+            f,
+            "sum: {} from {} of {} lines",
+            self.sum(),
+            self.lines.len() - errors,
+            self.lines.len()
+        )?;
+        if errors > 0 {
+            write!(f, ", {} without a digit", errors)?;
+        }
+        writeln!(f)
+    }
+}
+
+fn describe(line: &str, token: &Token) -> String {
+    let kind = if token.spelled { "spelled" } else { "numeral" };
+    format!(
+        "`{}` ({}, byte {})",
+        &line[token.start..token.end()],
+        kind,
+        token.start
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2;
+
+    #[test]
+    fn test_audit() {
+        let input = "two1nine\nabc\nab7c\n";
+        let audit = Audit::new(input, Some(&Vocabulary::english()));
+        assert_eq!(audit.lines.len(), 3);
+        assert_eq!(
+            audit.lines[0].tokens,
+            Ok((
+                Token {
+                    digit: 2,
+                    start: 0,
+                    len: 3,
+                    spelled: true
+                },
+                Token {
+                    digit: 9,
+                    start: 4,
+                    len: 4,
+                    spelled: true
+                }
+            ))
+        );
+        assert_eq!(audit.lines[2].value(), Some(77));
+        assert_eq!(audit.sum(), 29 + 77);
+
+        let errors: Vec<_> = audit.errors().collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (2, 1));
+        assert!(part2(input).is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "eightwo\nabc\nfünf7x\n";
+        let report = Audit::new(input, Some(&Vocabulary::english())).to_string();
+        assert_eq!(
+            report,
+            "1 | eightwo\n\
+             \x20 | ^^^^^^^ 82: first `eight` (spelled, byte 0), last `two` (spelled, byte 4)\n\
+             2 | abc\n\
+             \x20 = error: line 2, column 1: expected a digit, found `abc`\n\
+             3 | fünf7x\n\
+             \x20 |     ^ 77: only `7` (numeral, byte 5)\n\
+             sum: 159 from 2 of 3 lines, 1 without a digit\n"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Seeded calibration documents that stress the word matcher, with their
+//! expected sums.
+
+use crate::Vocabulary;
+
+/// What to generate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorOptions {
+    pub lines: usize,
+    /// How long the occasional very long line is, or 0 for none.
+    pub long_line_len: usize,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        Self {
+            lines: 1000,
+            long_line_len: 10_000,
+        }
+    }
+}
+
+/// A generated document and the sums each part should find in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub text: String,
+    /// `None` if some line has no numeral, which part 1 rejects.
+    pub part1: Option<u64>,
+    pub part2: u64,
+}
+
+/// Generates a document of lines meant to trip up a word matcher: chains of
+/// words that share letters, lines whose only digits are spelled out, words
+/// cut short, and very long lines with the digits at the far ends. The same
+/// seed always gives the same document.
+///
+/// Every line has at least one digit in `vocabulary`, so part 2 accepts the
+/// whole document. The expected sums are worked out by checking every
+/// substring of every line, independently of the scanner.
+pub fn generate(seed: u64, options: &GeneratorOptions, vocabulary: &Vocabulary) -> Document {
+    let mut generator = Generator::new(seed, vocabulary);
+    let mut text = String::new();
+    let mut part1 = Some(0);
+    let mut part2 = 0;
+    for _ in 0..options.lines {
+        let line = generator.line(options.long_line_len);
+        part1 = part1.and_then(|sum| Some(sum + naive_value(&line, None)?));
+        part2 += naive_value(&line, Some(vocabulary)).expect("every line has a digit");
+        text.push_str(&line);
+        text.push('\n');
+    }
+    Document { text, part1, part2 }
+}
+
+/// The calibration value of `line` found by comparing every substring with
+/// every token.
+fn naive_value(line: &str, vocabulary: Option<&Vocabulary>) -> Option<u64> {
+    let digit_at = |i: usize| {
+        let rest = &line[i..];
+        let numeral = rest.chars().next()?.to_digit(10);
+        numeral.or_else(|| {
+            vocabulary?
+                .words()
+                .filter(|(word, _)| rest.get(..word.len()) == Some(word))
+                .max_by_key(|(word, _)| word.len())
+                .map(|(_, digit)| digit)
+        })
+    };
+    let starts = (0..line.len()).filter(|&i| line.is_char_boundary(i));
+    let first = starts.clone().find_map(digit_at)?;
+    let last = starts.rev().find_map(digit_at)?;
+    Some((first * 10 + last) as u64)
+}
+
+/// SplitMix64, which is small and gives the same stream on every platform.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+struct Generator<'a> {
+    rng: Rng,
+    words: Vec<&'a str>,
+    /// For each word, the words that can follow it sharing some letters,
+    /// with the part of the follower that is not shared.
+    overlaps: Vec<Vec<(usize, &'a str)>>,
+    /// Letters that appear in no word, so filler cannot form or extend one.
+    filler: Vec<char>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(seed: u64, vocabulary: &'a Vocabulary) -> Self {
+        let words: Vec<&str> = vocabulary.words().map(|(word, _)| word).collect();
+        let overlaps = words
+            .iter()
+            .map(|a| {
+                let mut next = Vec::new();
+                for (j, b) in words.iter().enumerate() {
+                    for k in 1..a.len().min(b.len()) {
+                        if b.is_char_boundary(k) && a.ends_with(&b[..k]) {
+                            next.push((j, &b[k..]));
+                        }
+                    }
+                }
+                next
+            })
+            .collect();
+        let mut filler: Vec<char> = ('a'..='z')
+            .filter(|&c| !words.iter().any(|word| word.contains(c)))
+            .collect();
+        if filler.is_empty() {
+            filler.push('_');
+        }
+        Self {
+            rng: Rng(seed),
+            words,
+            overlaps,
+            filler,
+        }
+    }
+
+    fn line(&mut self, long_line_len: usize) -> String {
+        let mut line = String::new();
+        match self.rng.below(10) {
+            // A few words joined by shared letters, like "sevenineightwo".
+            0..=2 => {
+                self.filler(&mut line, 3);
+                self.chain(&mut line);
+                self.filler(&mut line, 3);
+            }
+            // Spelled digits only, with cut-short words around them.
+            3..=4 => {
+                self.filler(&mut line, 2);
+                self.decoy(&mut line);
+                for _ in 0..=self.rng.below(3) {
+                    self.chain(&mut line);
+                    self.filler(&mut line, 3);
+                    self.decoy(&mut line);
+                }
+            }
+            // Numerals and words mixed, with chains at both ends.
+            5..=7 => {
+                self.chain(&mut line);
+                for _ in 0..=self.rng.below(4) {
+                    self.filler(&mut line, 2);
+                    self.numeral(&mut line);
+                    self.decoy(&mut line);
+                }
+                self.chain(&mut line);
+            }
+            // A single numeral, which is both the first and the last digit.
+            8 => {
+                self.filler(&mut line, 4);
+                self.numeral(&mut line);
+                self.filler(&mut line, 4);
+            }
+            // Mostly filler with the digits at the far ends, or just a
+            // longer mixed line when long lines are turned off.
+            _ => {
+                self.chain(&mut line);
+                let target = line.len() + long_line_len.max(40);
+                while line.len() < target {
+                    self.filler(&mut line, 64);
+                    self.decoy(&mut line);
+                }
+                self.chain(&mut line);
+            }
+        }
+        line
+    }
+
+    /// Up to `max` filler letters.
+    fn filler(&mut self, line: &mut String, max: usize) {
+        for _ in 0..self.rng.below(max + 1) {
+            line.push(*self.rng.pick(&self.filler));
+        }
+    }
+
+    fn numeral(&mut self, line: &mut String) {
+        line.push(char::from(b'0' + self.rng.below(10) as u8));
+    }
+
+    /// A word with its last letter missing, so it almost matches.
+    fn decoy(&mut self, line: &mut String) {
+        let word = *self.rng.pick(&self.words);
+        let mut end = word.len() - 1;
+        while !word.is_char_boundary(end) {
+            end -= 1;
+        }
+        line.push_str(&word[..end]);
+        self.filler(line, 1);
+        // Make sure the cut-short word cannot run into what comes next.
+        line.push(self.filler[0]);
+    }
+
+    /// Up to five words, each sharing letters with the one before when the
+    /// vocabulary allows it.
+    fn chain(&mut self, line: &mut String) {
+        let mut current = self.rng.below(self.words.len());
+        line.push_str(self.words[current]);
+        for _ in 0..self.rng.below(5) {
+            if self.overlaps[current].is_empty() {
+                break;
+            }
+            let &(next, rest) = self.rng.pick(&self.overlaps[current]);
+            line.push_str(rest);
+            current = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+    use crate::{part1, part2_with, sum_lines, Audit};
+
+    #[test]
+    fn test_generate() {
+        let english = Vocabulary::english();
+        let options = GeneratorOptions {
+            lines: 300,
+            long_line_len: 2000,
+        };
+        for seed in 0..20 {
+            let document = generate(seed, &options, &english);
+            assert_eq!(document, generate(seed, &options, &english));
+            assert_eq!(document.text.lines().count(), options.lines);
+
+            let part2 = part2_with(&document.text, &english).unwrap();
+            assert_eq!(part2 as u64, document.part2, "seed {}", seed);
+            assert_eq!(part1(&document.text).ok().map(u64::from), document.part1);
+
+            let reader = BufReader::with_capacity(64, document.text.as_bytes());
+            assert_eq!(sum_lines(reader, Some(&english)).unwrap(), document.part2);
+            let audit = Audit::new(&document.text, Some(&english));
+            assert_eq!(audit.sum() as u64, document.part2);
+        }
+    }
+
+    #[test]
+    fn test_adversarial_lines() {
+        let english = Vocabulary::english();
+        let document = generate(7, &GeneratorOptions::default(), &english);
+        let lines: Vec<_> = document.text.lines().collect();
+        let spelled_only = lines
+            .iter()
+            .filter(|line| !line.bytes().any(|b| b.is_ascii_digit()));
+        assert!(spelled_only.count() > 100);
+        for overlap in ["twone", "oneight", "sevenine", "eightwo"] {
+            assert!(
+                lines.iter().any(|line| line.contains(overlap)),
+                "{}",
+                overlap
+            );
+        }
+        assert!(lines.iter().any(|line| line.len() >= 10_000));
+        assert_eq!(document.part1, None);
+    }
+
+    #[test]
+    fn test_other_vocabularies() {
+        for name in Vocabulary::preset_names() {
+            let vocabulary = Vocabulary::preset(name).unwrap();
+            let options = GeneratorOptions {
+                lines: 200,
+                long_line_len: 500,
+            };
+            let document = generate(1, &options, &vocabulary);
+            let sum = part2_with(&document.text, &vocabulary).unwrap();
+            assert_eq!(sum as u64, document.part2, "{}", name);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+mod audit;
+mod generate;
+mod scan;
+mod stream;
+mod vocabulary;
+
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
+
+pub use audit::{Audit, LineAudit};
+pub use generate::{generate, Document, GeneratorOptions};
+pub use stream::{sum_lines, StreamError};
+pub use vocabulary::Vocabulary;
+
+pub const DAY: Day = Day {
+    number: 1,
+    title: "Trebuchet?!",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day1,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let values = parse::lines(input, |line| calibration_value(line, None))?;
+    Ok(values.iter().sum())
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    part2_with(input, &Vocabulary::english())
+}
+
+/// Part 2 with digits spelled out in any vocabulary.
+pub fn part2_with(input: &str, vocabulary: &Vocabulary) -> Result<u32, ParseError> {
+    let values = parse::lines(input, |line| calibration_value(line, Some(vocabulary)))?;
+    Ok(values.iter().sum())
+}
+
+/// A digit found in a line, written as a numeral or spelled out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub digit: u32,
+    /// Byte offset of the token in its line.
+    pub start: usize,
+    /// Length of the token in bytes.
+    pub len: usize,
+    pub spelled: bool,
+}
+
+impl Token {
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// The first and last digit in `line` as a two digit number. With a
+/// vocabulary, digits may also be written out as words.
+fn calibration_value(line: &str, vocabulary: Option<&Vocabulary>) -> Result<u32, ParseError> {
+    match first_and_last(line, vocabulary) {
+        Some((first, last)) => Ok(first.digit * 10 + last.digit),
+        None => Err(ParseError::new(line, line, "a digit")),
+    }
+}
+
+/// The first and last digit token in `line`, which are the same token if
+/// there is only one.
+pub(crate) fn first_and_last(
+    line: &str,
+    vocabulary: Option<&Vocabulary>,
+) -> Option<(Token, Token)> {
+    first_and_last_bytes(line.as_bytes(), vocabulary)
+}
+
+/// `first_and_last` on raw bytes. The numerals are found a word at a time;
+/// words can only come before the first numeral or after the last one, so
+/// only those ends are checked a byte at a time. Every position there is
+/// checked for a word starting at it, so words that share letters, like
+/// "eightwo", are found from either end.
+pub(crate) fn first_and_last_bytes(
+    line: &[u8],
+    vocabulary: Option<&Vocabulary>,
+) -> Option<(Token, Token)> {
+    let first_numeral = scan::first_digit(line);
+    let last_numeral = scan::last_digit(line);
+    let (first, last) = match vocabulary {
+        None => (first_numeral?, last_numeral?),
+        Some(vocabulary) => {
+            let before = first_numeral.unwrap_or(line.len());
+            let first = (0..before)
+                .find(|&i| vocabulary.word_at(&line[i..]).is_some())
+                .or(first_numeral)?;
+            let after = last_numeral.map_or(first, |i| i.max(first));
+            let last = (after..line.len())
+                .rev()
+                .find(|&i| vocabulary.word_at(&line[i..]).is_some())
+                .unwrap_or(after);
+            (first, last)
+        }
+    };
+    let token = |i| token_at(line, i, vocabulary).expect("a token starts here");
+    Some((token(first), token(last)))
+}
+
+/// The token starting at byte `i` of `line`, if any.
+fn token_at(line: &[u8], i: usize, vocabulary: Option<&Vocabulary>) -> Option<Token> {
+    if line[i].is_ascii_digit() {
+        return Some(Token {
+            digit: (line[i] - b'0') as u32,
+            start: i,
+            len: 1,
+            spelled: false,
+        });
+    }
+    let (digit, len) = vocabulary?.word_at(&line[i..])?;
+    Some(Token {
+        digit,
+        start: i,
+        len,
+        spelled: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /// The calibration value of `line`, found by comparing every substring
+    /// against every token.
+    fn naive(line: &str) -> Option<u32> {
+        let mut digits = Vec::new();
+        for i in 0..line.len() {
+            for j in i + 1..=line.len() {
+                let token = &line[i..j];
+                if let Some(d) = token.parse::<u32>().ok().filter(|_| j == i + 1) {
+                    digits.push(d);
+                } else if let Some(w) = WORDS.iter().position(|&w| w == token) {
+                    digits.push(w as u32 + 1);
+                }
+            }
+        }
+        Some(digits.first()? * 10 + digits.last()?)
+    }
+
+    #[test]
+    fn test_examples() {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\
+                     4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+        assert_eq!(part2(input), Ok(281));
+        assert_eq!(
+            part1("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"),
+            Ok(142)
+        );
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let spanish = Vocabulary::preset("spanish").unwrap();
+        assert_eq!(part2_with("doscero\nseisiete\n", &spanish), Ok(20 + 67));
+        let german = Vocabulary::preset("german").unwrap();
+        assert_eq!(part2_with("xfünfzig3\n", &german), Ok(53));
+        let french = Vocabulary::preset("french").unwrap();
+        assert_eq!(part2_with("zéroneuf\n", &french), Ok(9));
+        assert_eq!(
+            part2_with("zero\n", &Vocabulary::english())
+                .unwrap_err()
+                .line,
+            1
+        );
+        assert_eq!(
+            part2_with("zero\n", &Vocabulary::preset("english-zero").unwrap()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let english = Vocabulary::english();
+        for (first, a) in (1..).zip(WORDS) {
+            for (last, b) in (1..).zip(WORDS) {
+                for k in 0..a.len().min(b.len()) {
+                    if a.ends_with(&b[..k]) {
+                        let line = format!("{}{}", a, &b[k..]);
+                        assert_eq!(
+                            calibration_value(&line, Some(&english)),
+                            Ok(first * 10 + last),
+                            "{}",
+                            line
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(calibration_value("eightwo", Some(&english)), Ok(82));
+        assert_eq!(calibration_value("oneight", Some(&english)), Ok(18));
+        assert_eq!(calibration_value("twone", Some(&english)), Ok(21));
+        assert_eq!(calibration_value("sevenine", Some(&english)), Ok(79));
+        assert_eq!(
+            calibration_value("eightwo", None).unwrap_err().expected,
+            "a digit"
+        );
+    }
+
+    #[test]
+    fn test_fragments() {
+        let english = Vocabulary::english();
+        // Words, partial words around them and digits, joined in every way.
+        let mut fragments = vec!["x", "0", "7", "zero"];
+        for word in WORDS {
+            fragments.push(word);
+            for k in 1..word.len() {
+                fragments.push(&word[..k]);
+                fragments.push(&word[k..]);
+            }
+        }
+        fragments.sort_unstable();
+        fragments.dedup();
+
+        for a in &fragments {
+            for b in &fragments {
+                for c in &fragments {
+                    let line = format!("{}{}{}", a, b, c);
+                    assert_eq!(
+                        calibration_value(&line, Some(&english)).ok(),
+                        naive(&line),
+                        "{}",
+                        line
+                    );
+                }
+            }
+        }
+    }
+}
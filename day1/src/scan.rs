@@ -0,0 +1,82 @@
+//! Finding ASCII digits eight bytes at a time.
+
+const LOW: u64 = 0x0101_0101_0101_0101;
+const HIGH: u64 = 0x8080_8080_8080_8080;
+
+/// A mask with the high bit set in each byte of `word` that is an ASCII digit.
+/// This is `hasbetween` from Bit Twiddling Hacks: masking off the high bits
+/// first keeps every byte's arithmetic from carrying into its neighbour, so
+/// the result is exact.
+fn digit_mask(word: u64) -> u64 {
+    let low7 = word & (LOW * 0x7f);
+    (LOW * (0x7f + b':' as u64) - low7) & !word & (low7 + LOW * (0x7f - b'/' as u64)) & HIGH
+}
+
+fn load(chunk: &[u8]) -> u64 {
+    u64::from_le_bytes(chunk.try_into().expect("chunks are eight bytes"))
+}
+
+/// The index of the first ASCII digit in `bytes`.
+pub(crate) fn first_digit(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(8);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let mask = digit_mask(load(chunk));
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+    let offset = bytes.len() - chunks.remainder().len();
+    let i = chunks.remainder().iter().position(u8::is_ascii_digit)?;
+    Some(offset + i)
+}
+
+/// The index of the last ASCII digit in `bytes`.
+pub(crate) fn last_digit(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.rchunks_exact(8);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let mask = digit_mask(load(chunk));
+        if mask != 0 {
+            let start = bytes.len() - (i + 1) * 8;
+            return Some(start + 7 - mask.leading_zeros() as usize / 8);
+        }
+    }
+    chunks.remainder().iter().rposition(u8::is_ascii_digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_mask() {
+        for byte in 0..=255u8 {
+            let mask = digit_mask(u64::from_le_bytes([
+                b'x', byte, 0, 0xff, byte, b'9', 0x80, 0,
+            ]));
+            let expected = if byte.is_ascii_digit() {
+                0x80 << 8 | 0x80 << 32
+            } else {
+                0
+            };
+            assert_eq!(mask, expected | 0x80 << 40, "{:#x}", byte);
+        }
+    }
+
+    #[test]
+    fn test_first_and_last_digit() {
+        // Every length up to three chunks, with digits at every pair of places.
+        for len in 0..24 {
+            let mut bytes = vec![b'a'; len];
+            assert_eq!((first_digit(&bytes), last_digit(&bytes)), (None, None));
+            for i in 0..len {
+                for j in i..len {
+                    bytes.fill(0xb0 + (len % 10) as u8);
+                    bytes[i] = b'1';
+                    bytes[j] = b'2';
+                    assert_eq!(first_digit(&bytes), Some(i), "{:?}", bytes);
+                    assert_eq!(last_digit(&bytes), Some(j), "{:?}", bytes);
+                }
+            }
+        }
+    }
+}
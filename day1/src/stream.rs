@@ -0,0 +1,160 @@
+//! Calibration sums over documents too large to read into memory.
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use aoc_core::ParseError;
+
+use crate::{first_and_last_bytes, Vocabulary};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// A line without a digit. The error's line number counts from the start
+    /// of the stream.
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "cannot read calibration document: {}", err),
+            StreamError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// The sum of the calibration values of every line in `reader`, as part 1
+/// reads them without a vocabulary and part 2 with one.
+///
+/// Lines are scanned in place in the reader's buffer. Only a line that
+/// straddles two fills of the buffer is copied, so memory use is bounded by
+/// the buffer plus the longest line, however large the document.
+pub fn sum_lines<R: BufRead>(
+    mut reader: R,
+    vocabulary: Option<&Vocabulary>,
+) -> Result<u64, StreamError> {
+    let mut sum = 0;
+    let mut line_number = 0;
+    let mut add = |line: &[u8]| {
+        line_number += 1;
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        match first_and_last_bytes(line, vocabulary) {
+            Some((first, last)) => {
+                sum += (first.digit * 10 + last.digit) as u64;
+                Ok(())
+            }
+            None => {
+                let line = String::from_utf8_lossy(line);
+                let mut err = ParseError::new(&line, &line, "a digit");
+                err.line = line_number;
+                Err(StreamError::Parse(err))
+            }
+        }
+    };
+
+    // The start of a line that continues in the next fill of the buffer.
+    let mut partial = Vec::new();
+    loop {
+        let buffer = match reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+        if buffer.is_empty() {
+            break;
+        }
+
+        let mut rest = buffer;
+        if !partial.is_empty() {
+            let end = memchr::memchr(b'\n', rest).unwrap_or(rest.len());
+            partial.extend_from_slice(&rest[..end]);
+            if end == rest.len() {
+                rest = &[];
+            } else {
+                add(&partial)?;
+                partial.clear();
+                rest = &rest[end + 1..];
+            }
+        }
+
+        let mut start = 0;
+        for end in memchr::memchr_iter(b'\n', rest) {
+            add(&rest[start..end])?;
+            start = end + 1;
+        }
+        partial.extend_from_slice(&rest[start..]);
+
+        let len = buffer.len();
+        reader.consume(len);
+    }
+
+    if !partial.is_empty() {
+        add(&partial)?;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::*;
+    use crate::{part1, part2};
+
+    const PART1: &str = "1abc2\npqr3stu8vwx\r\na1b2c3d4e5f\ntreb7uchet";
+    const PART2: &str = "two1nine\neightwothree\nabcone2threexyz\r\nxtwone3four\n\
+                         4nineeightseven2\nzoneight234\n7pqrstsixteen";
+
+    #[test]
+    fn test_sum_lines() {
+        let english = Vocabulary::english();
+        let expected = (part1(PART1).unwrap() as u64, part2(PART2).unwrap() as u64);
+        // Buffers of every size up to the longest line, so lines straddle
+        // fills at every possible place.
+        for capacity in 1..=20 {
+            let reader = |input: &'static str| BufReader::with_capacity(capacity, input.as_bytes());
+            let sums = (
+                sum_lines(reader(PART1), None).unwrap(),
+                sum_lines(reader(PART2), Some(&english)).unwrap(),
+            );
+            assert_eq!(sums, expected, "capacity {}", capacity);
+        }
+        assert_eq!(sum_lines(&b""[..], None).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "1abc2\nab\nx3\n";
+        for capacity in 1..=8 {
+            let reader = BufReader::with_capacity(capacity, input.as_bytes());
+            match sum_lines(reader, None) {
+                Err(StreamError::Parse(err)) => {
+                    assert_eq!(
+                        err.to_string(),
+                        "line 2, column 1: expected a digit, found `ab`"
+                    )
+                }
+                result => panic!("unexpected result {:?}", result),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use aoc_core::{parse, ParseError};
+
+/// Built-in vocabularies by name. Each lists the words for consecutive digits
+/// starting at the given one.
+const PRESETS: [(&str, u32, &[&str]); 5] = [
+    (
+        "english",
+        1,
+        &[
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "english-zero",
+        0,
+        &[
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "german",
+        0,
+        &[
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+    (
+        "french",
+        0,
+        &[
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ],
+    ),
+    (
+        "spanish",
+        0,
+        &[
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ],
+    ),
+];
+
+/// The words that count as digits in part 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// Grouped by first byte, and longest first within a group so that when
+    /// several words start at the same place the longest one wins.
+    words: Vec<(String, u32)>,
+    /// The words starting with byte `b` are `words[starts[b]..starts[b + 1]]`.
+    starts: Vec<usize>,
+}
+
+impl Vocabulary {
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let mut words: Vec<_> = words
+            .into_iter()
+            .map(|(word, digit)| (word.into(), digit))
+            .filter(|(word, _): &(String, u32)| !word.is_empty())
+            .collect();
+        words.sort_by(|(a, _), (b, _)| {
+            (a.as_bytes()[0], b.len(), a).cmp(&(b.as_bytes()[0], a.len(), b))
+        });
+        let starts = (0..=256)
+            .map(|byte| words.partition_point(|(word, _)| (word.as_bytes()[0] as usize) < byte))
+            .collect();
+        Self { words, starts }
+    }
+
+    /// The English words for one through nine, as in the puzzle.
+    pub fn english() -> Self {
+        Self::preset("english").expect("english is a preset")
+    }
+
+    /// The names `preset` accepts.
+    pub fn preset_names() -> impl Iterator<Item = &'static str> {
+        PRESETS.iter().map(|&(name, _, _)| name)
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        let (_, first, words) = PRESETS.iter().find(|&&(n, _, _)| n == name)?;
+        Some(Self::new(words.iter().copied().zip(*first..)))
+    }
+
+    /// Reads a vocabulary from lines of `word = digit`. Blank lines and lines
+    /// starting with `#` are ignored.
+    pub fn parse(config: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for line in config.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, digit) = parse::split_once(config, line, " = ")?;
+            if word.is_empty() || word.starts_with(|c: char| c.is_ascii_digit()) {
+                return Err(ParseError::new(config, word, "a word"));
+            }
+            match parse::number(config, digit)? {
+                value @ 0..=9 => words.push((word, value)),
+                _ => return Err(ParseError::new(config, digit, "a digit from 0 to 9")),
+            }
+        }
+        if words.is_empty() {
+            return Err(ParseError::end(config, "a `word = digit` line"));
+        }
+        Ok(Self::new(words))
+    }
+
+    /// Every word with the digit it stands for.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    /// The digit and length in bytes of the word `s` starts with.
+    pub(crate) fn word_at(&self, s: &[u8]) -> Option<(u32, usize)> {
+        let byte = *s.first()? as usize;
+        self.words[self.starts[byte]..self.starts[byte + 1]]
+            .iter()
+            .find(|(word, _)| s.starts_with(word.as_bytes()))
+            .map(|(word, digit)| (*digit, word.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        for name in Vocabulary::preset_names() {
+            let vocabulary = Vocabulary::preset(name).unwrap();
+            let mut digits: Vec<_> = vocabulary.words.iter().map(|&(_, d)| d).collect();
+            digits.sort_unstable();
+            assert_eq!(digits.last(), Some(&9), "{}", name);
+            assert!(digits.windows(2).all(|w| w[1] == w[0] + 1), "{}", name);
+        }
+        let german = Vocabulary::preset("german").unwrap();
+        assert_eq!(german.word_at("fünf".as_bytes()), Some((5, 5)));
+        assert_eq!(Vocabulary::preset("klingon"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = "# Dutch\neen = 1\ntwee = 2\n\ndrie = 3\n";
+        let dutch = Vocabulary::parse(config).unwrap();
+        assert_eq!(dutch.word_at(&b"tweeen"[3..]), Some((1, 3)));
+        assert_eq!(
+            dutch,
+            Vocabulary::new([("drie", 3), ("een", 1), ("twee", 2)])
+        );
+
+        let err = Vocabulary::parse("een = 1\ntwee = 12\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.expected, "a digit from 0 to 9");
+        let err = Vocabulary::parse("een 1\n").unwrap_err();
+        assert_eq!(err.expected, "` = `");
+        assert!(Vocabulary::parse("# nothing\n").is_err());
+        assert!(Vocabulary::parse(" = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let vocabulary = Vocabulary::new([("two", 2), ("twone", 9)]);
+        assert_eq!(vocabulary.word_at(b"twone"), Some((9, 5)));
+        assert_eq!(vocabulary.word_at(b"twon"), Some((2, 3)));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    Answer, Day, ParseError, Solution,
+};
+
+pub const DAY: Day = Day {
+    number: 10,
+    title: "Pipe Maze",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day10,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+fn opens(b: u8, d: Direction) -> bool {
+    matches!(
+        (b, d),
+        (b'S', _)
+            | (b'|', Direction::Down | Direction::Up)
+            | (b'-', Direction::Right | Direction::Left)
+            | (b'F', Direction::Right | Direction::Down)
+            | (b'L', Direction::Up | Direction::Right)
+            | (b'J', Direction::Left | Direction::Up)
+            | (b'7', Direction::Left | Direction::Down)
+    )
+}
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    const TILES: &str = "|-LJ7F.S";
+    let grid = Grid::parse(input, "one of `|` `-` `L` `J` `7` `F` `.` `S`", |c| {
+        TILES.contains(c).then_some(c as u8)
+    })?;
+    if grid.position(|&b| b == b'S').is_none() {
+        return Err(ParseError::end(input, "a start tile `S`"));
+    }
+    Ok(grid)
+}
+
+fn empty(g: &Grid<u8>, p: Point) -> bool {
+    g[p] == b'.'
+}
+
+// The pipes connected to the one at `p`.
+fn neigh(g: &Grid<u8>, p: Point) -> Vec<Point> {
+    Direction::ALL
+        .iter()
+        .filter(|&&d| opens(g[p], d))
+        .map(|&d| (p.step(d), d))
+        .filter(|&(n, d)| g.get(n).is_some_and(|&b| opens(b, d.opposite())))
+        .map(|(n, _)| n)
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Dist(Point, usize);
+
+// Walks the loop from `S` breadth-first. Returns the distance to the farthest
+// pipe along with every position on the loop.
+fn find_loop(g: &Grid<u8>) -> (usize, HashSet<Point>) {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    for (curr, _) in g.iter().filter(|(_, &b)| b == b'S') {
+        visited.insert(curr);
+        queue.push_back(Dist(curr, 0))
+    }
+    while let Some(Dist(p, d)) = queue.pop_front() {
+        if !empty(g, p) && neigh(g, p).iter().all(|n| visited.contains(n)) {
+            return (d + 1, visited);
+        }
+        for n in neigh(g, p) {
+            if !visited.contains(&n) {
+                visited.insert(n);
+                queue.push_back(Dist(n, d + 1))
+            }
+        }
+    }
+    unreachable!("no loop through S")
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let g = parse(input)?;
+    Ok(find_loop(&g).0)
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let g = parse(input)?;
+    let (_, visited) = find_loop(&g);
+    let mut c = 0;
+    for y in 0..g.height() {
+        let mut out = true;
+        for x in 0..g.width() {
+            let curr = Point::new(x as i32, y as i32);
+            let orig = g[curr];
+            if visited.contains(&curr)
+                && (orig == b'F' || orig == b'7' || orig == b'|' || orig == b'S')
+            {
+                out = !out;
+            }
+            if !out && !visited.contains(&curr) {
+                c += 1;
+            }
+        }
+    }
+    Ok(c)
+}
@@ -0,0 +1,54 @@
+use aoc_core::{grid::Grid, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 11,
+    title: "Cosmic Expansion",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day11,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    solve(input, 1)
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    solve(input, 999_999)
+}
+
+fn solve(input: &str, increment: usize) -> Result<usize, ParseError> {
+    let image = Grid::parse_tiles(input, ".#")?;
+    let (mut xx, mut yy) = (vec![0; image.width()], vec![0; image.height()]);
+    for (p, _) in image.iter().filter(|(_, &c)| c == '#') {
+        xx[p.x as usize] += 1;
+        yy[p.y as usize] += 1;
+    }
+
+    Ok(dist(&xx, increment) + dist(&yy, increment))
+}
+
+fn dist(counts: &[usize], increment: usize) -> usize {
+    let (mut gaps, mut sum, mut items, mut dist) = (0, 0, 0, 0);
+    for (i, count) in counts.iter().enumerate() {
+        if *count > 0 {
+            let expanded = i + increment * gaps;
+            dist += count * (items * expanded - sum);
+            sum += count * expanded;
+            items += count;
+        } else {
+            gaps += 1;
+        }
+    }
+    dist
+}
\ No newline at end of file
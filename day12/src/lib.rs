@@ -1,50 +1,56 @@
 use std::{collections::HashMap, iter::once};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    part1(input); // 7407
-    part2(input); // 30568243604962
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 12,
+    title: "Hot Springs",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day12,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(String, Vec<usize>)>, ParseError> {
+    parse::lines(input, |l| {
+        let (springs, groups) = parse::split_once(l, l, " ")?;
+        if let Some(i) = springs.find(|c| !".#?".contains(c)) {
+            return Err(ParseError::new(l, &springs[i..], "one of `.` `#` `?`"));
+        }
+        let groups = groups
+            .split(',')
+            .map(|v| parse::number(l, v))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((springs.to_string(), groups))
+    })
 }
 
-fn part1(input: &str) {
-    let data = input
-        .lines()
-        .map(|l| {
-            let mut f = l.split(' ');
-            let springs = f.next().unwrap().to_string();
-            let groups = f
-                .next()
-                .unwrap()
-                .split(',')
-                .map(|v| v.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
-            (springs, groups)
-        })
-        .collect::<Vec<_>>();
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let data = parse(input)?;
 
     let mut cache: HashMap<(String, Vec<usize>), usize> = HashMap::new();
-    let output = data
+    Ok(data
         .iter()
         .map(|v: &(String, Vec<usize>)| validate_springs(&v.0, &v.1, &mut cache))
-        .sum::<usize>();
-    println!("Output: {}", output);
+        .sum::<usize>())
 }
 
-fn part2(input: &str) {
-    let data = input
-        .lines()
-        .map(|l| {
-            let mut f = l.split(' ');
-            let springs = f.next().unwrap().to_string();
-            let groups = f
-                .next()
-                .unwrap()
-                .split(',')
-                .map(|v| v.parse::<usize>().unwrap())
-                .collect::<Vec<_>>();
-            (springs, groups)
-        })
-        .collect::<Vec<_>>();
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let data = parse(input)?;
 
     let mut sum = 0;
     let mut cache: HashMap<(String, Vec<usize>), usize> = HashMap::new();
@@ -64,7 +70,7 @@ fn part2(input: &str) {
         let result = validate_springs(&new_springs, &new_counts, &mut cache);
         sum += result
     }
-    println!("Output: {}", sum);
+    Ok(sum)
 }
 
 fn validate_springs(
@@ -75,12 +81,12 @@ fn validate_springs(
     let cache_key = (springs.to_string(), counts.to_vec());
     let result = if let Some(res) = cache.get(&cache_key) {
         *res
-    } else if springs.starts_with('.') {
-        validate_springs(&springs[1..], counts, cache)
-    } else if springs.starts_with('?') {
+    } else if let Some(rest) = springs.strip_prefix('.') {
+        validate_springs(rest, counts, cache)
+    } else if let Some(rest) = springs.strip_prefix('?') {
         let unknown_is_spring: String = springs.replacen('?', "#", 1);
         validate_springs(&unknown_is_spring, counts, cache)
-            + validate_springs(&springs[1..], counts, cache)
+            + validate_springs(rest, counts, cache)
     } else if springs.is_empty() {
         counts.is_empty() as usize
     } else if counts.is_empty() && springs.contains('#')
@@ -0,0 +1,91 @@
+use aoc_core::{grid::Grid, parse::blocks, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 13,
+    title: "Point of Incidence",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day13,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+#[derive(Clone)]
+struct Pattern {
+    grid: Grid<char>,
+}
+
+impl Pattern {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse_tiles(s, ".#")?,
+        })
+    }
+
+    // Rows above a horizontal line of reflection count a hundred times,
+    // columns left of a vertical one count once.
+    fn summary(&self, smudges: usize) -> usize {
+        if let Some(rows) = reflection(&self.grid, smudges) {
+            return rows * 100;
+        }
+        reflection(&self.grid.transpose(), smudges).unwrap_or(0)
+    }
+
+    fn part1(&self) -> usize {
+        self.summary(0)
+    }
+
+    fn part2(&self) -> usize {
+        self.summary(1)
+    }
+}
+
+fn rows_diff(grid: &Grid<char>, y1: usize, y2: usize) -> usize {
+    grid.row(y1)
+        .iter()
+        .zip(grid.row(y2))
+        .filter(|(a, b)| a != b)
+        .count()
+}
+
+/// The number of rows above the first horizontal line of reflection whose
+/// mirrored rows differ in exactly `smudges` tiles.
+fn reflection(grid: &Grid<char>, smudges: usize) -> Option<usize> {
+    (1..grid.height()).find(|&i| {
+        let mut diff = 0;
+        for (y1, y2) in (0..i).rev().zip(i..grid.height()) {
+            diff += rows_diff(grid, y1, y2);
+            if diff > smudges {
+                return false;
+            }
+        }
+        diff == smudges
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    blocks(input)
+        .map(|pattern| Pattern::parse(pattern).map_err(|err| err.within(input, pattern)))
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(parse(input)?.iter().map(|pattern| pattern.part1()).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(parse(input)?.iter().map(|pattern| pattern.part2()).sum())
+}
@@ -0,0 +1,99 @@
+use aoc_core::{geometry::Point, grid::Grid, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 14,
+    title: "Parabolic Reflector Dish",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day14,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Round,
+    Square,
+    Empty,
+}
+
+fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, "one of `.` `#` `O`", |c| match c {
+        '.' => Some(Tile::Empty),
+        '#' => Some(Tile::Square),
+        'O' => Some(Tile::Round),
+        _ => None,
+    })
+}
+
+fn slide_north(grid: &mut Grid<Tile>) {
+    for col in 0..grid.width() as i32 {
+        let mut empty_or_round_row = 0;
+        for row in 0..grid.height() as i32 {
+            let curr = grid[Point::new(col, row)];
+            match curr {
+                Tile::Square => empty_or_round_row = row + 1,
+                Tile::Round => {
+                    let target = Point::new(col, empty_or_round_row);
+                    let replace_with = std::mem::replace(&mut grid[target], curr);
+                    grid[Point::new(col, row)] = replace_with;
+                    empty_or_round_row += 1;
+                }
+                Tile::Empty => (),
+            }
+        }
+    }
+}
+
+fn weight(grid: &Grid<Tile>) -> usize {
+    grid.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, row)| {
+            let round_rocks = row.iter().filter(|tile| **tile == Tile::Round).count();
+            round_rocks * (i + 1)
+        })
+        .sum()
+}
+
+fn cycle(mut grid: Grid<Tile>) -> Grid<Tile> {
+    for _ in 0..4 {
+        slide_north(&mut grid);
+        grid = grid.rotate_clockwise();
+    }
+    grid
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let mut grid = parse(input)?;
+    slide_north(&mut grid);
+    Ok(weight(&grid))
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let mut grid = parse(input)?;
+    let mut seen = vec![grid.clone()];
+
+    loop {
+        grid = cycle(grid);
+        if let Some(idx) = seen.iter().position(|x| x == &grid) {
+            let cycle_len = seen.len() - idx;
+            let final_idx = idx + (1_000_000_000 - idx) % cycle_len;
+            return Ok(weight(&seen[final_idx]));
+        }
+        seen.push(grid.clone());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use aoc_core::{parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 15,
+    title: "Lens Library",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day15,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let sequence: Vec<&str> = input.trim().split(",").collect();
+    let mut sum = 0;
+    for seq in sequence {
+        sum += ascii_hash(seq);
+    }
+    sum
+}
+
+#[derive(Debug, Clone)]
+struct Box<'a> {
+    lenses: Vec<(&'a str, u8)>,
+}
+
+impl<'a> Box<'a> {
+    fn new() -> Self {
+        Self {
+            lenses: Vec::with_capacity(6),
+        }
+    }
+
+    fn add(&mut self, label: &'a str, focal_length: u8) {
+        if let Some(pos) = self.lenses.iter().position(|(l, _)| *l == label) {
+            self.lenses[pos].1 = focal_length;
+        } else {
+            self.lenses.push((label, focal_length));
+        }
+    }
+
+    fn remove(&mut self, label: &str) {
+        if let Some(pos) = self.lenses.iter().position(|(l, _)| *l == label) {
+            self.lenses.remove(pos);
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let sequence: Vec<&str> = input.trim().split(",").collect();
+    let mut boxes = vec![Box::new(); 256];
+
+    for seq in sequence {
+        if seq.ends_with("-") {
+            let (label, _) = seq.split_once("-").unwrap();
+            let hash = ascii_hash(label);
+            boxes[hash].remove(label);
+        } else {
+            let (label, focal_len) = parse::split_once(input, seq, "=")?;
+            let hash = ascii_hash(label);
+            let focal_length = parse::number(input, focal_len)?;
+            boxes[hash].add(label, focal_length);
+        }
+
+    }
+    Ok(boxes.iter().enumerate().filter(|(_, b)| !b.lenses.is_empty()).map(|(box_index, b)| {
+        b.lenses.iter().enumerate().map(|(lens_index, lens)| {
+            (1 + box_index) * (lens_index + 1) * lens.1 as usize
+        }).sum::<usize>()
+    }).sum::<usize>())
+}
+
+fn ascii_hash(s: &str) -> usize {
+    let mut hash = 0;
+    for c in s.chars() {
+        hash += c as usize;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
\ No newline at end of file
@@ -1,54 +1,76 @@
 use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    part1(input); // 7074
-    part2(input); // 7530
+use aoc_core::{geometry::Point, grid::Grid, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 16,
+    title: "The Floor Will Be Lava",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day16,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_tiles(input, "./\\|-")
 }
 
-fn part1(input: &str) {
-    let mut grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    let grid = parse(input)?;
     let start_point = (0, 0, 'r');
 
-    let output = traverse(start_point, &mut grid);
-    println!("Output: {}", output);
+    Ok(traverse(start_point, &grid))
 }
 
-fn part2(input: &str) {
-    let mut grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    let grid = parse(input)?;
     let mut max_count = 0;
-    for y in 0..grid.len() {
-        let count_visited = traverse((0, y, 'r'), &mut grid);
+    for y in 0..grid.height() {
+        let count_visited = traverse((0, y, 'r'), &grid);
         if count_visited > max_count {
             max_count = count_visited;
         }
-        let beam = (grid[0].len() - 1, y, 'l');
-        let count_visited = traverse(beam, &mut grid);
+        let beam = (grid.width() - 1, y, 'l');
+        let count_visited = traverse(beam, &grid);
         if count_visited > max_count {
             max_count = count_visited;
         }
     }
-    for x in 0..grid[0].len() {
-        let count_visited = traverse((x, 0, 'd'), &mut grid);
+    for x in 0..grid.width() {
+        let count_visited = traverse((x, 0, 'd'), &grid);
         if count_visited > max_count {
             max_count = count_visited;
         }
-        let count_visited = traverse((x, grid.len() - 1, 'u'), &mut grid);
+        let count_visited = traverse((x, grid.height() - 1, 'u'), &grid);
         if count_visited > max_count {
             max_count = count_visited;
         }
     }
-    println!("Output: {}", max_count);
+    Ok(max_count)
 }
 
-fn traverse(initial_beam: (usize, usize, char), grid: &mut Vec<Vec<char>>) -> i32 {
+fn traverse(initial_beam: (usize, usize, char), grid: &Grid<char>) -> i32 {
         let mut visited: HashSet<(usize, usize, char)> = HashSet::new();
         let mut queue: Vec<(usize, usize, char)> = Vec::new();
         queue.push(initial_beam);
         let mut count: HashMap<(usize, usize), usize> = HashMap::new();
     
-        let last_x = grid[0].len() - 1;
-        let last_y = grid.len() - 1;
+        let last_x = grid.width() - 1;
+        let last_y = grid.height() - 1;
         while !queue.is_empty() {
             let (x, y, beam_direction) = queue.remove(0);
             if !visited.insert((x, y, beam_direction)) {
@@ -56,7 +78,7 @@ fn traverse(initial_beam: (usize, usize, char), grid: &mut Vec<Vec<char>>) -> i3
             }
             *count.entry((x, y)).or_insert(0) += 1;
     
-            let current_cell = grid[y][x];
+            let current_cell = grid[Point::new(x as i32, y as i32)];
     
             match (beam_direction, current_cell) {
                 ('u', '/') => if x < last_x { queue.push((x + 1, y, 'r')) },
@@ -0,0 +1,120 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    Answer, Day, ParseError, Solution,
+};
+
+pub const DAY: Day = Day {
+    number: 17,
+    title: "Clumsy Crucible",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day17,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+pub fn part1(input: &str) -> Result<u16, ParseError> {
+    Ok(best_path::<1, 3>(parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u16, ParseError> {
+    Ok(best_path::<4, 10>(parse(input)?))
+}
+
+struct Tile {
+    heat_loss: u8,
+    g_scores: [u16; 2],
+}
+
+// Best scores are tracked per axis, since the next move always turns.
+fn axis(direction: Option<Direction>) -> usize {
+    match direction {
+        Some(d) if !d.is_vertical() => 1,
+        _ => 0,
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, "a digit", |c| {
+        Some(Tile {
+            heat_loss: c.to_digit(10)? as u8,
+            g_scores: [u16::MAX; 2],
+        })
+    })
+}
+
+fn at(x: usize, y: usize) -> Point {
+    Point::new(x as i32, y as i32)
+}
+
+fn best_path<const MIN_STEPS: usize, const MAX_STEPS: usize>(mut map: Grid<Tile>) -> u16 {
+    const START: (u8, u8) = (0, 0);
+    let goal = (map.width() - 1, map.height() - 1);
+
+    let mut frontier = BinaryHeap::new();
+    frontier.push((Reverse(0), None, START));
+
+    map[at(START.0 as usize, START.1 as usize)].g_scores = [0; 2];
+
+    while let Some((Reverse(g_score), direction, (x, y))) = frontier.pop() {
+        let (x, y) = (x as usize, y as usize);
+
+        if g_score != map[at(x, y)].g_scores[axis(direction)] {
+            continue;
+        }
+
+        if (x, y) == goal {
+            return g_score;
+        }
+
+        for new_direction in match direction {
+            Some(Direction::Up | Direction::Down) => [Direction::Right, Direction::Left],
+            Some(Direction::Right | Direction::Left) => [Direction::Up, Direction::Down],
+            None => [Direction::Down, Direction::Right], // Special case for starting tile
+        } {
+            let mut tentative_g_score = g_score;
+            for steps in 1..=MAX_STEPS {
+                let (new_x, new_y) = match new_direction {
+                    Direction::Up => (x, y.wrapping_sub(steps)),
+                    Direction::Down => (x, y + steps),
+                    Direction::Right => (x + steps, y),
+                    Direction::Left => (x.wrapping_sub(steps), y),
+                };
+
+                if new_x >= map.width() || new_y >= map.height() {
+                    continue;
+                }
+
+                let neighbor = &mut map[at(new_x, new_y)];
+                tentative_g_score += neighbor.heat_loss as u16;
+
+                if steps >= MIN_STEPS {
+                    let old_g_score = neighbor.g_scores[axis(Some(new_direction))];
+
+                    if tentative_g_score < old_g_score {
+                        neighbor.g_scores[axis(Some(new_direction))] = tentative_g_score;
+                        frontier.push((Reverse(tentative_g_score), Some(new_direction), (new_x as u8, new_y as u8)))
+                    }
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 18,
+    title: "Lavaduct Lagoon",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day18,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+/// One dig instruction. The colour code holds a second direction and
+/// distance, which part 2 uses instead.
+struct Instruction {
+    dir: u8,
+    steps: isize,
+    color_dir: u8,
+    color_steps: isize,
+}
+
+pub fn part1(input: &str) -> Result<isize, ParseError> {
+    let mut curr = (0, 0);
+    let mut vertices = vec![curr];
+    let mut boundary = 1;
+    for Instruction { dir, steps, .. } in parse(input)? {
+        curr = proceed(curr, dir, steps);
+        vertices.push(curr);
+        boundary += steps;
+    }
+    Ok(solve(&vertices, boundary))
+}
+
+pub fn part2(input: &str) -> Result<isize, ParseError> {
+    let mut curr = (0, 0);
+    let mut vertices = vec![curr];
+    let mut boundary = 1;
+    for Instruction { color_dir, color_steps, .. } in parse(input)? {
+        curr = proceed(curr, color_dir, color_steps);
+        vertices.push(curr);
+        boundary += color_steps;
+    }
+    Ok(solve(&vertices, boundary))
+}
+
+fn solve(vertices: &[(isize, isize)], boundary: isize) -> isize {
+    let area: isize = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.0 * b.1 - a.1 * b.0)
+        .sum();
+    area.abs() / 2 + boundary / 2 + 1
+}
+
+const fn proceed(curr: (isize, isize), dir: u8, steps: isize) -> (isize, isize) {
+    match dir {
+        b'U' | b'3' => (curr.0, curr.1 - steps),
+        b'D' | b'1' => (curr.0, curr.1 + steps),
+        b'R' | b'0' => (curr.0 + steps, curr.1),
+        b'L' | b'2' => (curr.0 - steps, curr.1),
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input, |line| {
+        let (dir, rest) = parse::split_once(line, line, " ")?;
+        if !matches!(dir, "U" | "D" | "L" | "R") {
+            return Err(ParseError::new(line, dir, "one of `U` `D` `L` `R`"));
+        }
+        let (steps, color) = parse::split_once(line, rest, " ")?;
+        let steps = parse::number(line, steps)?;
+
+        let color = parse::strip_prefix(line, color, "(#")?;
+        let (color, _) = parse::split_once(line, color, ")")?;
+        let color_dir = color.bytes().last().filter(|b| (b'0'..=b'3').contains(b));
+        let color_steps = color
+            .get(..5)
+            .filter(|_| color.len() == 6)
+            .and_then(|hex| isize::from_str_radix(hex, 16).ok());
+        let (Some(color_dir), Some(color_steps)) = (color_dir, color_steps) else {
+            return Err(ParseError::new(line, color, "a colour code like `70c710`"));
+        };
+
+        Ok(Instruction {
+            dir: dir.as_bytes()[0],
+            steps,
+            color_dir,
+            color_steps,
+        })
+    })
+}
@@ -0,0 +1,239 @@
+use std::{cmp::{min, max}, collections::{HashMap, hash_map::Entry::{Vacant, Occupied}}};
+
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 19,
+    title: "Aplenty",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day19,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(|input: &str| {
+            WorkflowParser::parse_workflows(input, &mut input.lines())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Condition {
+    True,
+    Greater(u8, u16),
+    Less(u8, u16),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Accept,
+    Reject,
+    Workflow(usize),
+}
+
+type Workflows = Vec<Vec<(Condition, Target)>>;
+
+struct WorkflowParser<'a> {
+    indexes_by_name: HashMap<&'a str, usize>,
+    next_index: usize,
+    // Every workflow name used as a target, to check they all get defined.
+    targets: Vec<&'a str>,
+}
+
+impl<'a> WorkflowParser<'a> {
+    fn get_index(&mut self, name: &'a str) -> usize {
+        match self.indexes_by_name.entry(name) {
+            Vacant(entry) => {
+                let index = self.next_index;
+                entry.insert(index);
+                self.next_index += 1;
+                index
+            },
+            Occupied(entry) => *entry.get(),
+        }
+    }
+
+    fn get_target(&mut self, target_str: &'a str) -> Target {
+        match target_str {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => {
+                self.targets.push(target_str);
+                Target::Workflow(self.get_index(target_str))
+            }
+        }
+    }
+
+    fn parse_val_name(input: &str, name: &str) -> Result<u8, ParseError> {
+        match ["x", "m", "a", "s"].iter().position(|&x| x == name) {
+            Some(val) => Ok(val as u8),
+            None => Err(ParseError::new(input, name, "one of `x` `m` `a` `s`")),
+        }
+    }
+
+    fn parse_rule(&mut self, input: &str, part: &'a str) -> Result<(Condition, Target), ParseError> {
+        let Some((condition, target_str)) = part.split_once(':') else {
+            return Ok((Condition::True, self.get_target(part)));
+        };
+
+        let condition = if let Some((val, num)) = condition.split_once('>') {
+            Condition::Greater(Self::parse_val_name(input, val)?, parse::number(input, num)?)
+        } else if let Some((val, num)) = condition.split_once('<') {
+            Condition::Less(Self::parse_val_name(input, val)?, parse::number(input, num)?)
+        } else {
+            return Err(ParseError::new(input, condition, "a comparison like `a<2006`"));
+        };
+        Ok((condition, self.get_target(target_str)))
+    }
+
+    /// Parses workflows from `lines`, which must be lines of `input`, up to the
+    /// first blank line.
+    fn parse_workflows<T: Iterator<Item = &'a str>>(input: &str, lines: &mut T) -> Result<(Workflows, usize), ParseError> {
+        let mut parser = Self {
+            indexes_by_name: HashMap::new(),
+            next_index: 0,
+            targets: Vec::new(),
+        };
+        let mut workflows = Vec::new();
+        let mut defined = Vec::new();
+
+        while let Some(line) = lines.next().filter(|&x| !x.is_empty()) {
+            let (name, rules) = parse::split_once(input, line, "{")?;
+            let Some(rules) = rules.strip_suffix('}') else {
+                return Err(ParseError::new(input, &line[line.len()..], "`}`"));
+            };
+
+            let index = parser.get_index(name);
+            let rules = rules
+                .split(',')
+                .map(|part| parser.parse_rule(input, part))
+                .collect::<Result<Vec<_>, _>>()?;
+            if !matches!(rules.last(), Some((Condition::True, _))) {
+                return Err(ParseError::new(input, &line[line.len() - 1..], "a fallback rule"));
+            }
+
+            if workflows.len() <= index {
+                workflows.resize(index + 1, vec![]);
+                defined.resize(index + 1, false);
+            }
+
+            workflows[index] = rules;
+            defined[index] = true;
+        }
+
+        for target in &parser.targets {
+            if !defined[parser.indexes_by_name[target]] {
+                return Err(ParseError::new(input, target, "a defined workflow"));
+            }
+        }
+
+        let start_workflow = match parser.indexes_by_name.get("in") {
+            Some(&index) if defined[index] => index,
+            _ => return Err(ParseError::end(input, "a workflow named `in`")),
+        };
+
+        Ok((workflows, start_workflow))
+    }
+}
+
+/// Parses a part's ratings, like `{x=787,m=2655,a=1222,s=2876}`.
+fn parse_ratings(input: &str, line: &str) -> Result<[u16; 4], ParseError> {
+    let ratings = parse::strip_prefix(input, line, "{")?;
+    let Some(ratings) = ratings.strip_suffix('}') else {
+        return Err(ParseError::new(input, &line[line.len()..], "`}`"));
+    };
+
+    let mut values = [0; 4];
+    let mut ratings = ratings.split(',');
+    for value in &mut values {
+        let rating = ratings
+            .next()
+            .ok_or_else(|| ParseError::new(input, &line[line.len() - 1..], "a rating"))?;
+        let (_, num) = parse::split_once(input, rating, "=")?;
+        *value = parse::number(input, num)?;
+    }
+    Ok(values)
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let mut lines = input.lines();
+    let (workflows, start_workflow) = WorkflowParser::parse_workflows(input, &mut lines)?;
+    let parts = lines
+        .map(|line| parse_ratings(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(parts.into_iter().filter_map(|values| {
+
+        let mut workflow_index = start_workflow;
+        let mut rule_index = 0;
+
+        loop {
+            let (condition, target) = workflows[workflow_index][rule_index];
+            if match condition {
+                Condition::Greater(val, num) => values[val as usize] > num,
+                Condition::Less(val, num) => values[val as usize] < num,
+                Condition::True => true,
+            } {
+                match target {
+                    Target::Accept => { break Some(values.into_iter().map(|x| x as u32).sum::<u32>()); }
+                    Target::Reject => { break None; }
+                    Target::Workflow(index) => { workflow_index = index; }
+                }
+                rule_index = 0;
+            } else {
+                rule_index += 1;
+            }
+        }
+    }).sum())
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let (workflows, start_workflow) = WorkflowParser::parse_workflows(input, &mut input.lines())?;
+
+    let mut possibilities = vec![(start_workflow, [[1, 4000]; 4])];
+    let mut possible_combinations = 0;
+
+    while let Some((index, mut ranges)) = possibilities.pop() {
+        for &(condition, target) in &workflows[index] {
+            let mut matching = ranges;
+            match condition {
+                Condition::Greater(val, num) => {
+                    matching[val as usize][0] = max(matching[val as usize][0], num + 1);
+                    ranges[val as usize][1] = min(ranges[val as usize][1], num);
+                }
+
+                Condition::Less(val, num) => {
+                    matching[val as usize][1] = min(matching[val as usize][1], num - 1);
+                    ranges[val as usize][0] = max(ranges[val as usize][0], num);
+                }
+
+                Condition::True => {
+                    ranges = [[1, 0]; 4];
+                }
+            }
+
+            match target {
+                Target::Accept => {
+                    possible_combinations += matching.into_iter().map(|x| if x[0] > x[1] { 0 } else { (x[1] - x[0] + 1) as u64 }).product::<u64>();
+                }
+
+                Target::Reject => {}
+                Target::Workflow(index) => {
+                    possibilities.push((index, matching));
+                }
+            }
+        }
+    }
+
+    Ok(possible_combinations)
+}
\ No newline at end of file
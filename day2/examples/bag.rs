@@ -0,0 +1,57 @@
+// Checks every game against a bag of your choosing, written like a draw or
+// read from a file with a colour or more per line, and prints the minimal bag
+// of each game, the draw each impossible game fails on, and the possible ids.
+//
+//     cargo run --release -p day2 --example bag -- "20 red, 13 green, 14 blue"
+//     cargo run --release -p day2 --example bag -- --file bag.txt day2/input1.txt
+
+use std::{fs, process::ExitCode};
+
+use aoc_core::Input;
+use day2::{Bag, Feasibility, DAY};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (spec, rest) = match args.as_slice() {
+        [flag, path, rest @ ..] if flag == "--file" => match fs::read_to_string(path) {
+            Ok(spec) => (spec, rest),
+            Err(err) => {
+                eprintln!("error: cannot read {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        },
+        [spec, rest @ ..] if !spec.starts_with("--") => (spec.clone(), rest),
+        _ => {
+            eprintln!("usage: bag <bag|--file path> [input path|-]");
+            return ExitCode::FAILURE;
+        }
+    };
+    let bag = match Bag::parse(&spec) {
+        Ok(bag) => bag,
+        Err(err) => {
+            eprintln!("error: bag: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = rest
+        .first()
+        .map_or(Input::Bundled, |arg| Input::from_arg(arg));
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match Feasibility::check(&input, &bag) {
+        Ok(feasibility) => {
+            print!("{}", feasibility);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
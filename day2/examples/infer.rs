@@ -0,0 +1,57 @@
+// Estimates the bag every game was played with from the draws alone, with how
+// sure it is of each colour and the draws that pin each one down.
+//
+//     cargo run --release -p day2 --example infer
+//     cargo run --release -p day2 --example infer -- --total 60 day2/input1.txt
+
+use std::process::ExitCode;
+
+use aoc_core::Input;
+use day2::{Inference, Record, DAY};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let usage = "usage: infer [--total cubes] [input path|-]";
+    let (total, rest) = match args.as_slice() {
+        [flag, total, rest @ ..] if flag == "--total" => match total.parse() {
+            Ok(total) => (Some(total), rest),
+            Err(_) => {
+                eprintln!("{}", usage);
+                return ExitCode::FAILURE;
+            }
+        },
+        [flag, ..] if flag.starts_with("--") => {
+            eprintln!("{}", usage);
+            return ExitCode::FAILURE;
+        }
+        rest => (None, rest),
+    };
+    let input = rest
+        .first()
+        .map_or(Input::Bundled, |arg| Input::from_arg(arg));
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let record = match Record::parse(&input) {
+        Ok(record) => record,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match Inference::new(&record, total) {
+        Ok(inference) => {
+            print!("{}", inference);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Prints statistics over every draw in a game record: how often each colour
+// comes up in what numbers, how the colours move together, the spread of the
+// minimal bags' powers, and the longest and shortest games.
+//
+//     cargo run --release -p day2 --example stats
+//     cargo run --release -p day2 --example stats -- --format json day2/input1.txt
+
+use std::process::ExitCode;
+
+use aoc_core::Input;
+use day2::{Record, Stats, DAY};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let usage = "usage: stats [--format text|json] [input path|-]";
+    let (json, rest) = match args.as_slice() {
+        [flag, format, rest @ ..] if flag == "--format" => match format.as_str() {
+            "text" => (false, rest),
+            "json" => (true, rest),
+            _ => {
+                eprintln!("{}", usage);
+                return ExitCode::FAILURE;
+            }
+        },
+        [flag, ..] if flag.starts_with("--") => {
+            eprintln!("{}", usage);
+            return ExitCode::FAILURE;
+        }
+        rest => (false, rest),
+    };
+    let input = rest
+        .first()
+        .map_or(Input::Bundled, |arg| Input::from_arg(arg));
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let record = match Record::parse(&input) {
+        Ok(record) => record,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let stats = Stats::new(&record);
+    if json {
+        println!("{:#}", stats.to_json());
+    } else {
+        print!("{}", stats);
+    }
+    ExitCode::SUCCESS
+}
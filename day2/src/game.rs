@@ -0,0 +1,332 @@
+use aoc_core::{parse, ParseError};
+
+/// The colours cubes come in. Draws and bags store their counts by index into
+/// this table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Colours {
+    names: Vec<String>,
+}
+
+impl Colours {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, colour: usize) -> &str {
+        &self.names[colour]
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn intern(&mut self, name: &str) -> usize {
+        self.index(name).unwrap_or_else(|| {
+            self.names.push(name.to_string());
+            self.names.len() - 1
+        })
+    }
+
+    /// What a colour outside the table is reported as not being.
+    fn expected(&self) -> String {
+        let names: Vec<_> = self.names.iter().map(|n| format!("`{}`", n)).collect();
+        format!("one of {}", names.join(" "))
+    }
+}
+
+/// The cubes revealed in one handful, counted by colour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Draw {
+    /// Indexed by colour. Colours past the end were not drawn.
+    counts: Vec<u32>,
+}
+
+impl Draw {
+    pub fn count(&self, colour: usize) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The colours drawn, with how many of each.
+    pub fn cubes(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(colour, &count)| (colour, count))
+    }
+
+    /// Parses `3 blue, 4 red`, adding new colours to `colours` unless
+    /// `closed`, in which case they are reported.
+    fn parse(src: &str, s: &str, colours: &mut Colours, closed: bool) -> Result<Self, ParseError> {
+        Self::parse_listed(src, s, colours, closed).map(|(draw, _)| draw)
+    }
+
+    /// Like `parse`, but also gives the colours in the order they are listed,
+    /// including any listed with a count of 0.
+    fn parse_listed(
+        src: &str,
+        s: &str,
+        colours: &mut Colours,
+        closed: bool,
+    ) -> Result<(Self, Vec<usize>), ParseError> {
+        let mut counts = Vec::new();
+        let mut listed = Vec::new();
+        for cubes in s.split(", ") {
+            let (count, name) = parse::split_once(src, cubes, " ")?;
+            let count = parse::number(src, count)?;
+            if name.is_empty() || !name.chars().all(char::is_alphabetic) {
+                return Err(ParseError::new(src, name, "a colour"));
+            }
+            let colour = match colours.index(name) {
+                Some(colour) => colour,
+                None if closed => {
+                    return Err(ParseError::new(src, name, colours.expected()));
+                }
+                None => colours.intern(name),
+            };
+            if listed.contains(&colour) {
+                let expected = format!("a colour other than `{}`, which was already drawn", name);
+                return Err(ParseError::new(src, name, expected));
+            }
+            if counts.len() <= colour {
+                counts.resize(colour + 1, 0);
+            }
+            counts[colour] = count;
+            listed.push(colour);
+        }
+        Ok((Self { counts }, listed))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// The fewest cubes of each colour that could have been in the bag.
+    pub fn minimal_bag(&self, colours: &Colours) -> Vec<u32> {
+        (0..colours.len())
+            .map(|colour| {
+                let counts = self.draws.iter().map(|draw| draw.count(colour));
+                counts.max().unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// The product of the minimal bag's counts over the colours this game
+    /// draws, so that colours only other games use do not make it 0. `None`
+    /// if it does not fit in a `u128`, which takes more than four colours.
+    pub fn power(&self) -> Option<u128> {
+        let mut bag: Vec<u32> = Vec::new();
+        for (colour, count) in self.draws.iter().flat_map(Draw::cubes) {
+            if bag.len() <= colour {
+                bag.resize(colour + 1, 0);
+            }
+            bag[colour] = bag[colour].max(count);
+        }
+        bag.iter()
+            .filter(|&&count| count > 0)
+            .try_fold(1u128, |power, &count| power.checked_mul(u128::from(count)))
+    }
+
+    /// Whether every draw could have come out of `bag`. The game's colours
+    /// must come from the bag's table.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.first_failure(bag).is_none()
+    }
+
+    /// The first draw with more cubes of some colour than `bag` holds.
+    pub fn first_failure(&self, bag: &Bag) -> Option<Failure> {
+        self.draws.iter().enumerate().find_map(|(draw, cubes)| {
+            let (colour, drawn) = cubes
+                .cubes()
+                .find(|&(colour, count)| count > bag.count(colour))?;
+            Some(Failure {
+                draw,
+                colour,
+                drawn,
+                available: bag.count(colour),
+            })
+        })
+    }
+
+    fn parse(line: &str, colours: &mut Colours, closed: bool) -> Result<Self, ParseError> {
+        let (game, draws) = parse::split_once(line, line, ": ")?;
+        let id = parse::strip_prefix(line, game, "Game ")?;
+        let id = parse::number(line, id)?;
+        let draws = draws
+            .split("; ")
+            .map(|draw| Draw::parse(line, draw, colours, closed))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, draws })
+    }
+}
+
+/// A draw that could not have come out of a bag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure {
+    /// 0-based index of the draw in its game.
+    pub draw: usize,
+    pub colour: usize,
+    pub drawn: u32,
+    /// How many cubes of that colour the bag holds.
+    pub available: u32,
+}
+
+/// Every game in a puzzle input, and the colours they use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub colours: Colours,
+    pub games: Vec<Game>,
+}
+
+impl Record {
+    /// Parses games in any colours.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, Colours::default(), false)
+    }
+
+    /// Parses games to be checked against `bag`. A colour the bag does not
+    /// have is an error, and the record shares the bag's colour table.
+    pub fn parse_for_bag(input: &str, bag: &Bag) -> Result<Self, ParseError> {
+        Self::parse_with(input, bag.colours.clone(), true)
+    }
+
+    fn parse_with(input: &str, mut colours: Colours, closed: bool) -> Result<Self, ParseError> {
+        let games = parse::lines(input, |line| Game::parse(line, &mut colours, closed))?;
+        Ok(Self { colours, games })
+    }
+}
+
+/// How many cubes of each colour a bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    pub colours: Colours,
+    counts: Vec<u32>,
+}
+
+impl Bag {
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Self::parse("12 red, 13 green, 14 blue").expect("the puzzle bag parses")
+    }
+
+    /// A bag with `counts[colour]` cubes of each colour in `colours`.
+    pub fn new(colours: Colours, counts: Vec<u32>) -> Self {
+        Self { colours, counts }
+    }
+
+    /// Parses a bag written like a draw, as in `12 red, 13 green, 14 blue`.
+    /// The colours may also be spread over several lines, as in a file, where
+    /// blank lines and lines starting with `#` are ignored.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut colours = Colours::default();
+        let mut counts: Vec<u32> = Vec::new();
+        let mut seen: Vec<bool> = Vec::new();
+        for line in s.lines().map(str::trim_end) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (draw, listed) = Draw::parse_listed(s, line, &mut colours, false)?;
+            counts.resize(colours.len(), 0);
+            seen.resize(colours.len(), false);
+            for (i, colour) in listed.into_iter().enumerate() {
+                if seen[colour] {
+                    let cubes = line.split(", ").nth(i).unwrap_or(line);
+                    let at = cubes.split_once(' ').map_or(cubes, |(_, name)| name);
+                    let expected = format!(
+                        "a colour other than `{}`, which is already in the bag",
+                        colours.name(colour)
+                    );
+                    return Err(ParseError::new(s, at, expected));
+                }
+                seen[colour] = true;
+                counts[colour] = draw.count(colour);
+            }
+        }
+        if colours.is_empty() {
+            return Err(ParseError::end(s, "a count and a colour"));
+        }
+        Ok(Self { colours, counts })
+    }
+
+    pub fn count(&self, colour: usize) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let record =
+            Record::parse("Game 1: 3 blue, 4 red; 1 red, 2 teal\nGame 7: 5 teal\n").unwrap();
+        assert_eq!(
+            record.colours.names().collect::<Vec<_>>(),
+            ["blue", "red", "teal"]
+        );
+        assert_eq!(record.games[1].id, 7);
+        assert_eq!(
+            record.games[0].draws[1].cubes().collect::<Vec<_>>(),
+            [(1, 1), (2, 2)]
+        );
+        assert_eq!(record.games[0].minimal_bag(&record.colours), [3, 4, 2]);
+
+        let err = Record::parse("Game 1: 3 blue, 4 blue\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 19));
+        let err = Record::parse("Game 1: 0 red, 2 red\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 18));
+        let err = Record::parse("Game 1: 3 blue,4 red\n").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (11, "a colour"));
+    }
+
+    #[test]
+    fn test_bag() {
+        let bag = Bag::puzzle();
+        let record =
+            Record::parse_for_bag("Game 1: 3 blue\nGame 2: 13 red, 1 green\n", &bag).unwrap();
+        assert!(record.games[0].is_possible(&bag));
+        assert!(!record.games[1].is_possible(&bag));
+
+        let err = Record::parse_for_bag("Game 1: 3 blue\nGame 2: 1 teal\n", &bag).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 11: expected one of `red` `green` `blue`, found `teal`"
+        );
+        assert!(Bag::parse("12 red, x green").is_err());
+        let err = Bag::parse("0 red\n12 green, 3 red\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 13));
+    }
+
+    #[test]
+    fn test_power() {
+        // Game 1 never draws red or green, so they do not count against it.
+        let record = Record::parse("Game 1: 3 blue\nGame 2: 1 red, 2 green\n").unwrap();
+        assert_eq!(record.games[0].power(), Some(3));
+        assert_eq!(record.games[1].power(), Some(2));
+
+        let huge = |colours: &[&str]| {
+            let cubes: Vec<_> = colours
+                .iter()
+                .map(|c| format!("4000000000 {}", c))
+                .collect();
+            Record::parse(&format!("Game 1: {}\n", cubes.join(", "))).unwrap()
+        };
+        let record = huge(&["red", "green", "blue"]);
+        assert_eq!(record.games[0].power(), Some(4_000_000_000u128.pow(3)));
+        let record = huge(&["red", "green", "blue", "teal", "pink"]);
+        assert_eq!(record.games[0].power(), None);
+    }
+}
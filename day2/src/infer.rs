@@ -0,0 +1,507 @@
+//! Estimating the bag every game was played with from the draws alone.
+//!
+//! Each draw is taken to be a handful pulled from the bag without
+//! replacement, so its chance is the multivariate hypergeometric
+//! `C(n_red, x_red) C(n_green, x_green) ... / C(total, handful)`. For a fixed
+//! total that splits into a factor per colour, and each factor grows by less
+//! with every cube added, so handing out cubes one at a time to the colour
+//! whose draws gain the most finds the most likely bag.
+//!
+//! Both the search and the confidences take time in proportion to the cubes
+//! above the minimal bag, so totals into the millions take about a second.
+//! Past `POSTERIOR_BLOCKS` spare cubes the confidences are worked out over
+//! blocks of cubes rather than cube by cube, and are close rather than exact.
+
+use std::{error::Error, fmt};
+
+use crate::{Bag, Colours, Record};
+
+/// The most likely count of one colour.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    /// The most cubes of the colour seen in one draw, which the bag must hold.
+    pub minimum: u32,
+    pub count: u32,
+    /// The chance of `count` given the draws, with every bag of the total
+    /// that could have given them equally likely beforehand.
+    pub confidence: f64,
+    /// The draws that showed `minimum` cubes, as game ids and 0-based draw
+    /// indexes. These are the draws that pin the colour down.
+    pub constraining: Vec<(u32, usize)>,
+}
+
+/// The most likely bag behind a record, colour by colour.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inference {
+    pub colours: Colours,
+    pub total: u32,
+    /// Whether the total was given rather than chosen.
+    pub total_given: bool,
+    pub estimates: Vec<Estimate>,
+    /// The chance of the whole estimated bag, on the same terms as each
+    /// colour's.
+    pub confidence: f64,
+}
+
+/// A total smaller than the minimal bag, which no draw could have come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooFewCubes {
+    pub total: u32,
+    pub minimum: u32,
+}
+
+impl fmt::Display for TooFewCubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a bag of {} cubes cannot have given these draws, which need at least {}",
+            self.total, self.minimum
+        )
+    }
+}
+
+impl Error for TooFewCubes {}
+
+/// What the draws say about one colour.
+struct Colour {
+    /// How many draws showed each count of the colour, for counts above 0.
+    drawn: Vec<(u32, u32)>,
+}
+
+impl Colour {
+    /// How much likelier the draws are with `n + 1` cubes of the colour than
+    /// with `n`, as a log.
+    fn gain(&self, n: u32) -> f64 {
+        self.drawn
+            .iter()
+            .map(|&(x, draws)| draws as f64 * ((n + 1) as f64 / (n + 1 - x) as f64).ln())
+            .sum()
+    }
+}
+
+/// Counts of `(value, occurrences)` for the non-zero values in `values`.
+fn histogram(values: impl Iterator<Item = u32>) -> Vec<(u32, u32)> {
+    let mut histogram: Vec<(u32, u32)> = Vec::new();
+    for value in values.filter(|&value| value > 0) {
+        match histogram.iter_mut().find(|(v, _)| *v == value) {
+            Some((_, count)) => *count += 1,
+            None => histogram.push((value, 1)),
+        }
+    }
+    histogram
+}
+
+impl Inference {
+    /// Estimates the bag behind every game in `record`, taking them all to
+    /// have been played with the same bag.
+    ///
+    /// With a `total`, the bag holds exactly that many cubes. Without one,
+    /// the total is the likeliest between the minimal bag's and twice that:
+    /// the draws alone can keep favouring ever larger bags with the same
+    /// proportions, so some bound is needed.
+    pub fn new(record: &Record, total: Option<u32>) -> Result<Self, TooFewCubes> {
+        let draws = || record.games.iter().flat_map(|game| &game.draws);
+        let colours: Vec<Colour> = (0..record.colours.len())
+            .map(|colour| Colour {
+                drawn: histogram(draws().map(|draw| draw.count(colour))),
+            })
+            .collect();
+        let handfuls = Colour {
+            drawn: histogram(draws().map(|draw| draw.cubes().map(|(_, n)| n).sum())),
+        };
+        let minimums: Vec<u32> = (0..colours.len())
+            .map(|colour| draws().map(|draw| draw.count(colour)).max().unwrap_or(0))
+            .collect();
+        let minimum: u32 = minimums.iter().sum();
+        let slack = match total {
+            Some(total) if total < minimum => return Err(TooFewCubes { total, minimum }),
+            // With no colours there is nowhere to put more cubes.
+            _ if colours.is_empty() => 0,
+            Some(total) => total - minimum,
+            None => minimum,
+        };
+
+        // Hand out the cubes above the minimal bag, keeping the likeliest
+        // total seen when it is not fixed. The handfuls' shared denominator
+        // only matters when the total changes.
+        let mut counts = minimums.clone();
+        let mut gains: Vec<f64> = (colours.iter().zip(&counts))
+            .map(|(colour, &n)| colour.gain(n))
+            .collect();
+        let mut likelihood = 0.0;
+        let mut best = (0.0, 0, counts.clone());
+        for added in 0..slack {
+            let colour = likeliest(&gains);
+            counts[colour] += 1;
+            if total.is_none() {
+                likelihood += gains[colour] - handfuls.gain(minimum + added);
+            }
+            gains[colour] = colours[colour].gain(counts[colour]);
+            if total.is_some() && added + 1 == slack {
+                best = (likelihood, slack, counts.clone());
+            } else if total.is_none() && likelihood > best.0 + 1e-9 {
+                best = (likelihood, added + 1, counts.clone());
+            }
+        }
+        let (_, slack, counts) = best;
+
+        let (marginals, confidence) = posterior(&colours, &minimums, &counts, slack);
+        let estimates = (0..colours.len())
+            .map(|colour| {
+                let constraining = record
+                    .games
+                    .iter()
+                    .flat_map(|game| {
+                        let draws = game.draws.iter().enumerate();
+                        draws.map(move |(i, draw)| (game.id, i, draw.count(colour)))
+                    })
+                    .filter(|&(_, _, n)| n > 0 && n == minimums[colour])
+                    .map(|(id, i, _)| (id, i))
+                    .collect();
+                Estimate {
+                    minimum: minimums[colour],
+                    count: counts[colour],
+                    confidence: marginals[colour],
+                    constraining,
+                }
+            })
+            .collect();
+        Ok(Self {
+            colours: record.colours.clone(),
+            total: minimum + slack,
+            total_given: total.is_some(),
+            estimates,
+            confidence,
+        })
+    }
+
+    /// The estimated bag.
+    pub fn bag(&self) -> Bag {
+        let counts = self.estimates.iter().map(|e| e.count).collect();
+        Bag::new(self.colours.clone(), counts)
+    }
+}
+
+/// A table of the estimate colour by colour, naming the first few draws that
+/// pin each one down.
+impl fmt::Display for Inference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let how = if self.total_given {
+            "as given".to_string()
+        } else {
+            let minimum: u32 = self.estimates.iter().map(|e| e.minimum).sum();
+            let mut how = format!("the likeliest from {} to {}", minimum, minimum * 2);
+            if minimum > 0 && self.total == minimum * 2 {
+                how.push_str(", and larger bags may be likelier still");
+            }
+            how
+        };
+        writeln!(f, "total: {} cubes, {}", self.total, how)?;
+        let width = self.colours.names().map(str::len).fold(6, usize::max);
+        writeln!(
+            f,
+            "{:<width$}  least  likely  confidence  pinned by",
+            "colour",
+            width = width
+        )?;
+        for (name, estimate) in self.colours.names().zip(&self.estimates) {
+            let draws: Vec<String> = estimate
+                .constraining
+                .iter()
+                .take(3)
+                .map(|&(id, draw)| format!("game {} draw {}", id, draw + 1))
+                .collect();
+            write!(
+                f,
+                "{:<width$}  {:>5}  {:>6}  {:>9.1}%  {}",
+                name,
+                estimate.minimum,
+                estimate.count,
+                estimate.confidence * 100.0,
+                draws.join(", "),
+                width = width
+            )?;
+            match estimate.constraining.len() {
+                n if n > 3 => writeln!(f, " and {} more", n - 3)?,
+                _ => writeln!(f)?,
+            }
+        }
+        let bag: Vec<String> = self
+            .colours
+            .names()
+            .zip(&self.estimates)
+            .map(|(name, estimate)| format!("{} {}", estimate.count, name))
+            .collect();
+        writeln!(
+            f,
+            "most likely bag: {} ({:.1}%)",
+            bag.join(", "),
+            self.confidence * 100.0
+        )
+    }
+}
+
+/// The colour whose draws gain the most from one more cube, the first of
+/// any tied.
+fn likeliest(gains: &[f64]) -> usize {
+    let best = gains
+        .iter()
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (i, &gain)| {
+            if gain > best.1 {
+                (i, gain)
+            } else {
+                best
+            }
+        });
+    best.0
+}
+
+/// How many blocks the spare cubes are split into at most when working out
+/// the confidences, which bounds the convolutions below.
+const POSTERIOR_BLOCKS: usize = 2048;
+
+/// The chance of each colour's estimated count, and of the whole estimate,
+/// over every way of adding `slack` cubes to the minimal bag.
+fn posterior(colours: &[Colour], minimums: &[u32], counts: &[u32], slack: u32) -> (Vec<f64>, f64) {
+    posterior_in_blocks(colours, minimums, counts, slack, POSTERIOR_BLOCKS)
+}
+
+/// `posterior`, with the spare cubes counted in at most `blocks` blocks. Each
+/// colour's weights are summed over every block, and the ways to add the
+/// rest of the cubes are read off at the block holding that many. With a
+/// block per cube this is exact.
+fn posterior_in_blocks(
+    colours: &[Colour],
+    minimums: &[u32],
+    counts: &[u32],
+    slack: u32,
+    blocks: usize,
+) -> (Vec<f64>, f64) {
+    // A single colour takes every spare cube, so there is nothing to weigh.
+    if colours.len() <= 1 {
+        return (vec![1.0; colours.len()], 1.0);
+    }
+    let slack = slack as usize;
+    let step = slack.div_ceil(blocks).max(1);
+    let block = |s: usize| (s + step / 2) / step;
+    let len = block(slack) + 1;
+
+    // Tilting every colour's likelihood by the gain of the next cube the
+    // estimate would take leaves the posterior alone, since every bag adds
+    // the same number of cubes, but puts each colour's peak at its estimate.
+    // Scaling the peaks to 1 then keeps the sums below from underflowing.
+    let tilt = (0..colours.len())
+        .map(|i| colours[i].gain(counts[i]))
+        .fold(f64::NEG_INFINITY, f64::max);
+    // The log weights are walked twice, once for the peak and the estimate's
+    // weight and once to sum them into blocks, so that nothing as long as
+    // the slack is kept.
+    let logs = |i: usize| {
+        let (colour, minimum) = (&colours[i], minimums[i]);
+        (0..=slack).scan(0.0, move |log, s| {
+            if s > 0 {
+                *log += colour.gain(minimum + s as u32 - 1) - tilt;
+            }
+            Some(*log)
+        })
+    };
+    let mut estimates = Vec::new();
+    let masses: Vec<Vec<f64>> = (0..colours.len())
+        .map(|i| {
+            let estimate = (counts[i] - minimums[i]) as usize;
+            let (mut peak, mut at_estimate) = (f64::NEG_INFINITY, 0.0);
+            for (s, log) in logs(i).enumerate() {
+                peak = peak.max(log);
+                if s == estimate {
+                    at_estimate = log;
+                }
+            }
+            let mut mass = vec![0.0; len];
+            for (s, log) in logs(i).enumerate() {
+                mass[block(s)] += (log - peak).exp();
+            }
+            estimates.push((at_estimate - peak).exp());
+            mass
+        })
+        .collect();
+
+    // prefix[i] and suffix[i] weigh the ways to add the blocks of cubes to
+    // the colours before and from i.
+    let mut identity = vec![0.0; len];
+    identity[0] = 1.0;
+    let mut prefix = vec![identity.clone()];
+    for mass in &masses {
+        prefix.push(convolve(prefix.last().unwrap(), mass));
+    }
+    let mut suffix = vec![identity];
+    for mass in masses.iter().rev() {
+        suffix.push(convolve(suffix.last().unwrap(), mass));
+    }
+    suffix.reverse();
+    let total = prefix[colours.len()][block(slack)];
+
+    // Each block of the others' ways holds about `step` totals, as does the
+    // block of every way, so the marginals need no correction but the joint,
+    // a single bag, does.
+    let marginals = (0..colours.len())
+        .map(|i| {
+            let rest = block(slack - (counts[i] - minimums[i]) as usize);
+            let others: f64 = (0..=rest)
+                .map(|j| prefix[i][j] * suffix[i + 1][rest - j])
+                .sum();
+            (estimates[i] * others / total).min(1.0)
+        })
+        .collect();
+    let joint = estimates.iter().product::<f64>() * step as f64 / total;
+    (marginals, joint.min(1.0))
+}
+
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    (0..a.len())
+        .map(|n| (0..=n).map(|j| a[j] * b[n - j]).sum())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer() {
+        let record =
+            Record::parse("Game 1: 2 red, 1 blue; 3 red\nGame 2: 2 red; 1 blue\n").unwrap();
+        let inference = Inference::new(&record, Some(4)).unwrap();
+        assert_eq!(inference.bag(), Bag::parse("3 red, 1 blue").unwrap());
+        assert_eq!(inference.estimates[0].constraining, [(1, 1)]);
+        assert_eq!(inference.confidence, 1.0);
+
+        // Red turns up about four times as often as blue, so it gets most of
+        // the spare cubes.
+        let inference = Inference::new(&record, Some(10)).unwrap();
+        assert_eq!(inference.total, 10);
+        assert_eq!(inference.bag(), Bag::parse("8 red, 2 blue").unwrap());
+        let red = &inference.estimates[0];
+        assert!(red.confidence < 1.0);
+        // With two colours, either count fixes the whole bag.
+        assert!((red.confidence - inference.confidence).abs() < 1e-12);
+        assert!((inference.estimates[1].confidence - inference.confidence).abs() < 1e-12);
+
+        let err = Inference::new(&record, Some(3)).unwrap_err();
+        assert_eq!(
+            err,
+            TooFewCubes {
+                total: 3,
+                minimum: 4
+            }
+        );
+
+        // Draws that keep showing every cube of the bag favour the minimal one.
+        let record = Record::parse("Game 1: 2 red, 2 blue; 2 red, 2 blue\n").unwrap();
+        let inference = Inference::new(&record, None).unwrap();
+        assert_eq!(inference.bag(), Bag::parse("2 red, 2 blue").unwrap());
+        assert!(inference
+            .to_string()
+            .starts_with("total: 4 cubes, the likeliest from 4 to 8\n"));
+
+        // Draws that rarely show the whole of a colour favour larger bags.
+        let record = Record::parse("Game 1: 2 red; 2 blue; 2 red; 2 blue\n").unwrap();
+        let inference = Inference::new(&record, None).unwrap();
+        assert_eq!(inference.total, 8);
+        assert!(inference
+            .to_string()
+            .contains(", and larger bags may be likelier still\n"));
+    }
+
+    #[test]
+    fn test_large_totals() {
+        let record = Record::parse(
+            "Game 1: 3 red, 1 green; 2 blue\nGame 2: 1 red, 2 green\nGame 3: 2 red\n",
+        )
+        .unwrap();
+        let inference = Inference::new(&record, Some(1_000_000)).unwrap();
+        let counts: u32 = inference.estimates.iter().map(|e| e.count).sum();
+        assert_eq!(counts, 1_000_000);
+        for estimate in &inference.estimates {
+            assert!(estimate.confidence > 0.0 && estimate.confidence <= 1.0);
+        }
+
+        // Blocks of cubes give close to the same confidences as single cubes.
+        let total = 3000;
+        let inference = Inference::new(&record, Some(total)).unwrap();
+        let minimums: Vec<u32> = inference.estimates.iter().map(|e| e.minimum).collect();
+        let counts: Vec<u32> = inference.estimates.iter().map(|e| e.count).collect();
+        let colours: Vec<Colour> = (0..3)
+            .map(|colour| Colour {
+                drawn: histogram(
+                    (record.games.iter().flat_map(|game| &game.draws)).map(|d| d.count(colour)),
+                ),
+            })
+            .collect();
+        let slack = total - minimums.iter().sum::<u32>();
+        let exact = posterior_in_blocks(&colours, &minimums, &counts, slack, usize::MAX);
+        let blocked = posterior_in_blocks(&colours, &minimums, &counts, slack, 64);
+        for (exact, blocked) in exact.0.iter().zip(&blocked.0) {
+            assert!(
+                (exact - blocked).abs() < 0.05 * exact,
+                "{} {}",
+                exact,
+                blocked
+            );
+        }
+        assert!((exact.1 - blocked.1).abs() < 0.05 * exact.1);
+    }
+
+    #[test]
+    fn test_posterior() {
+        // Brute force over every bag of the total, weighing each by the
+        // chance of the draws.
+        let record = Record::parse(
+            "Game 1: 3 red, 1 green; 2 blue\nGame 2: 1 red, 2 green\nGame 3: 2 red\n",
+        )
+        .unwrap();
+        let total = 14;
+        let inference = Inference::new(&record, Some(total)).unwrap();
+        let choose = |n: u32, k: u32| {
+            (0..k)
+                .map(|i| (n - i) as f64 / (k - i) as f64)
+                .product::<f64>()
+        };
+        let mut bags = Vec::new();
+        for red in 3..=total {
+            for green in 2..=total - red {
+                let blue = total - red - green;
+                if blue < 2 {
+                    continue;
+                }
+                let counts = [red, green, blue];
+                let weight: f64 = record
+                    .games
+                    .iter()
+                    .flat_map(|game| &game.draws)
+                    .map(|draw| {
+                        (0..3)
+                            .map(|colour| choose(counts[colour], draw.count(colour)))
+                            .product::<f64>()
+                    })
+                    .product();
+                bags.push((counts, weight));
+            }
+        }
+        let sum: f64 = bags.iter().map(|(_, w)| w).sum();
+        let (best, weight) = bags
+            .iter()
+            .fold(bags[0], |a, &b| if b.1 > a.1 { b } else { a });
+        let estimate: Vec<u32> = inference.estimates.iter().map(|e| e.count).collect();
+        assert_eq!(estimate, best);
+        assert!((inference.confidence - weight / sum).abs() < 1e-9);
+        for colour in 0..3 {
+            let marginal: f64 = bags
+                .iter()
+                .filter(|(counts, _)| counts[colour] == best[colour])
+                .map(|(_, w)| w / sum)
+                .sum();
+            assert!((inference.estimates[colour].confidence - marginal).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+mod game;
+mod infer;
+mod query;
+mod stats;
+
+use aoc_core::{Answer, Day, ParseError, Solution};
+
+pub use game::{Bag, Colours, Draw, Failure, Game, Record};
+pub use infer::{Estimate, Inference, TooFewCubes};
+pub use query::{Feasibility, Verdict};
+pub use stats::{ColourStats, Extreme, Stats};
+
+pub const DAY: Day = Day {
+    number: 2,
+    title: "Cube Conundrum",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day2,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(Record::parse)
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let bag = Bag::puzzle();
+    let record = Record::parse_for_bag(input, &bag)?;
+    Ok(record
+        .games
+        .iter()
+        .filter(|game| game.is_possible(&bag))
+        .map(|game| game.id)
+        .sum())
+}
+
+/// The sum of every game's power. It is an error for a power or the sum to
+/// be too large for an `Answer`.
+pub fn part2(input: &str) -> Result<i128, ParseError> {
+    let record = Record::parse(input)?;
+    let mut sum: i128 = 0;
+    for (game, line) in record.games.iter().zip(input.lines()) {
+        let power = game.power().and_then(|power| i128::try_from(power).ok());
+        sum = power
+            .and_then(|power| sum.checked_add(power))
+            .ok_or_else(|| ParseError::new(input, line, "powers that add up to below 2^127"))?;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_powers() {
+        let game = |id| {
+            format!(
+                "Game {}: 4000000000 red, 4000000000 blue, 4000000000 green\n",
+                id
+            )
+        };
+        assert_eq!(part2(&game(1)).unwrap(), 4_000_000_000i128.pow(3));
+        let input = format!(
+            "{}{}",
+            game(1),
+            game(2).replace("green", "green, 4000000000 teal")
+        );
+        assert_eq!(part2(&input).unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,153 @@
+//! Checking a record against any bag.
+
+use std::fmt;
+
+use aoc_core::ParseError;
+
+use crate::{Bag, Failure, Record};
+
+/// What one game says about a bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub id: u32,
+    /// The fewest cubes of each colour the game needs, indexed like the bag.
+    pub minimal_bag: Vec<u32>,
+    /// The first draw the bag could not have given, if any.
+    pub failure: Option<Failure>,
+}
+
+impl Verdict {
+    pub fn is_possible(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Every game in a record checked against one bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feasibility {
+    pub bag: Bag,
+    pub verdicts: Vec<Verdict>,
+}
+
+impl Feasibility {
+    /// Parses `input` against `bag` and checks every game.
+    pub fn check(input: &str, bag: &Bag) -> Result<Self, ParseError> {
+        let record = Record::parse_for_bag(input, bag)?;
+        let verdicts = record
+            .games
+            .iter()
+            .map(|game| Verdict {
+                id: game.id,
+                minimal_bag: game.minimal_bag(&record.colours),
+                failure: game.first_failure(bag),
+            })
+            .collect();
+        Ok(Self {
+            bag: bag.clone(),
+            verdicts,
+        })
+    }
+
+    /// The ids of the games the bag could have played.
+    pub fn possible_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.verdicts
+            .iter()
+            .filter(|verdict| verdict.is_possible())
+            .map(|verdict| verdict.id)
+    }
+}
+
+/// A table with a row per game, then the possible ids and their sum.
+impl fmt::Display for Feasibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let colours = &self.bag.colours;
+        let widths: Vec<usize> = colours.names().map(|name| name.len().max(3)).collect();
+        let bag: Vec<String> = colours
+            .names()
+            .enumerate()
+            .map(|(colour, name)| format!("{} {}", self.bag.count(colour), name))
+            .collect();
+        writeln!(f, "bag: {}", bag.join(", "))?;
+        write!(f, "game")?;
+        for (name, width) in colours.names().zip(&widths) {
+            write!(f, "  {:>width$}", name, width = width)?;
+        }
+        writeln!(f, "  result")?;
+        for verdict in &self.verdicts {
+            write!(f, "{:>4}", verdict.id)?;
+            for (count, width) in verdict.minimal_bag.iter().zip(&widths) {
+                write!(f, "  {:>width$}", count, width = width)?;
+            }
+            match verdict.failure {
+                None => writeln!(f, "  possible")?,
+                Some(failure) => writeln!(
+                    f,
+                    "  impossible: draw {} has {} {}, the bag has {}",
+                    failure.draw + 1,
+                    failure.drawn,
+                    colours.name(failure.colour),
+                    failure.available
+                )?,
+            }
+        }
+        let ids: Vec<String> = self.possible_ids().map(|id| id.to_string()).collect();
+        let sum: u32 = self.possible_ids().sum();
+        write!(
+            f,
+            "possible: {} of {} games",
+            ids.len(),
+            self.verdicts.len()
+        )?;
+        if ids.is_empty() {
+            writeln!(f)
+        } else {
+            writeln!(f, ", ids {} (sum {})", ids.join(" "), sum)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_check() {
+        let feasibility = Feasibility::check(EXAMPLE, &Bag::puzzle()).unwrap();
+        assert_eq!(feasibility.possible_ids().collect::<Vec<_>>(), [1, 2, 5]);
+        assert_eq!(feasibility.verdicts[3].minimal_bag, [14, 3, 15]);
+        assert_eq!(
+            feasibility.verdicts[3].failure,
+            Some(Failure {
+                draw: 2,
+                colour: 0,
+                drawn: 14,
+                available: 12
+            })
+        );
+        let table = feasibility.to_string();
+        assert!(table
+            .contains("   3   20     13     6  impossible: draw 1 has 20 red, the bag has 12\n"));
+        assert!(table.ends_with("possible: 3 of 5 games, ids 1 2 5 (sum 8)\n"));
+
+        // A bag from a file, in a different order and without green.
+        let bag = Bag::parse("# what if\n20 red\n\n15 blue\n").unwrap();
+        let err = Feasibility::check(EXAMPLE, &bag).unwrap_err();
+        assert_eq!(err.expected, "one of `red` `blue`");
+        let bag = Bag::parse("15 blue, 20 red\n13 green\n").unwrap();
+        let feasibility = Feasibility::check(EXAMPLE, &bag).unwrap();
+        assert_eq!(feasibility.possible_ids().sum::<u32>(), 15);
+        assert_eq!(feasibility.verdicts[0].minimal_bag, [6, 4, 2]);
+
+        let err = Bag::parse("12 red\n3 red\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!(Bag::parse("# empty\n").is_err());
+    }
+}
@@ -0,0 +1,374 @@
+//! Statistics over every draw in a record, for looking at the games as data
+//! rather than answers.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use serde_json::{json, Value};
+
+use crate::{Colours, Record};
+
+/// How one colour was drawn across every game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColourStats {
+    /// How many draws showed each number of cubes of the colour, including
+    /// the draws without it. Only the numbers some draw showed are present.
+    pub histogram: BTreeMap<u32, u32>,
+    pub cubes: u64,
+    pub mean: f64,
+}
+
+/// The games with the most or the fewest draws.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extreme {
+    pub draws: usize,
+    pub ids: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub colours: Colours,
+    pub games: usize,
+    pub draws: usize,
+    pub per_colour: Vec<ColourStats>,
+    /// The power of every game's minimal bag, in ascending order, leaving
+    /// out the games in `oversized`.
+    pub powers: Vec<u128>,
+    /// The ids of the games whose power does not fit in a `u128`.
+    pub oversized: Vec<u32>,
+    /// The Pearson correlation between the counts of each pair of colours
+    /// within a draw, or `None` when either count never varies.
+    pub correlations: Vec<Vec<Option<f64>>>,
+    pub most_draws: Extreme,
+    pub fewest_draws: Extreme,
+}
+
+impl Stats {
+    pub fn new(record: &Record) -> Self {
+        let colours = record.colours.len();
+        let draws: Vec<_> = record.games.iter().flat_map(|game| &game.draws).collect();
+        let counts: Vec<Vec<u32>> = (0..colours)
+            .map(|colour| draws.iter().map(|draw| draw.count(colour)).collect())
+            .collect();
+
+        let per_colour = counts
+            .iter()
+            .map(|counts| {
+                let mut histogram = BTreeMap::new();
+                for &count in counts {
+                    *histogram.entry(count).or_insert(0) += 1;
+                }
+                let cubes: u64 = counts.iter().map(|&count| u64::from(count)).sum();
+                ColourStats {
+                    histogram,
+                    cubes,
+                    mean: cubes as f64 / counts.len().max(1) as f64,
+                }
+            })
+            .collect();
+
+        let (mut powers, mut oversized) = (Vec::new(), Vec::new());
+        for game in &record.games {
+            match game.power() {
+                Some(power) => powers.push(power),
+                None => oversized.push(game.id),
+            }
+        }
+        powers.sort_unstable();
+
+        let correlations = (0..colours)
+            .map(|a| {
+                (0..colours)
+                    .map(|b| correlation(&counts[a], &counts[b]))
+                    .collect()
+            })
+            .collect();
+
+        let extreme = |draws: Option<usize>| {
+            let draws = draws.unwrap_or(0);
+            let games = record.games.iter().filter(|game| game.draws.len() == draws);
+            Extreme {
+                draws,
+                ids: games.map(|game| game.id).collect(),
+            }
+        };
+        let lengths = || record.games.iter().map(|game| game.draws.len());
+        Self {
+            colours: record.colours.clone(),
+            games: record.games.len(),
+            draws: draws.len(),
+            per_colour,
+            powers,
+            oversized,
+            correlations,
+            most_draws: extreme(lengths().max()),
+            fewest_draws: extreme(lengths().min()),
+        }
+    }
+
+    /// The power at quantile `q` between 0 and 1, taking the nearest rank.
+    pub fn power_quantile(&self, q: f64) -> Option<u128> {
+        let last = self.powers.len().checked_sub(1)?;
+        Some(self.powers[(q * last as f64).round() as usize])
+    }
+
+    /// The mean power, added up as floats so that large powers cannot
+    /// overflow.
+    pub fn power_mean(&self) -> Option<f64> {
+        let sum: f64 = self.powers.iter().map(|&power| power as f64).sum();
+        (!self.powers.is_empty()).then(|| sum / self.powers.len() as f64)
+    }
+
+    /// The powers counted in `buckets` equal ranges from the least to the
+    /// greatest, as each range's first value and its count. Asking for no
+    /// buckets gives one. Ranges that would start past `u128::MAX` are left
+    /// off, as no power can fall in them.
+    pub fn power_histogram(&self, buckets: usize) -> Vec<(u128, u32)> {
+        let (Some(&low), Some(&high)) = (self.powers.first(), self.powers.last()) else {
+            return Vec::new();
+        };
+        let buckets = buckets.max(1) as u128;
+        let width = (high - low) / buckets + 1;
+        let mut histogram: Vec<(u128, u32)> = (0..buckets)
+            .map_while(|i| Some((low.checked_add(i.checked_mul(width)?)?, 0)))
+            .collect();
+        for &power in &self.powers {
+            histogram[((power - low) / width) as usize].1 += 1;
+        }
+        while histogram.last().is_some_and(|&(_, count)| count == 0) {
+            histogram.pop();
+        }
+        histogram
+    }
+
+    pub fn to_json(&self) -> Value {
+        let colours: Vec<Value> = self
+            .colours
+            .names()
+            .zip(&self.per_colour)
+            .enumerate()
+            .map(|(i, (name, stats))| {
+                let correlations: serde_json::Map<String, Value> = self
+                    .colours
+                    .names()
+                    .zip(&self.correlations[i])
+                    .map(|(other, r)| (other.to_string(), json!(r)))
+                    .collect();
+                json!({
+                    "name": name,
+                    "cubes": stats.cubes,
+                    "mean": stats.mean,
+                    "histogram": stats.histogram,
+                    "correlations": correlations,
+                })
+            })
+            .collect();
+        let buckets: Vec<Value> = self
+            .power_histogram(POWER_BUCKETS)
+            .iter()
+            .map(|&(from, games)| json!({ "from": power_json(from), "games": games }))
+            .collect();
+        json!({
+            "games": self.games,
+            "draws": self.draws,
+            "colours": colours,
+            "powers": {
+                "min": self.powers.first().map(|&power| power_json(power)),
+                "median": self.power_quantile(0.5).map(power_json),
+                "max": self.powers.last().map(|&power| power_json(power)),
+                "mean": self.power_mean(),
+                "histogram": buckets,
+                "oversized": self.oversized,
+            },
+            "most_draws": { "draws": self.most_draws.draws, "ids": self.most_draws.ids },
+            "fewest_draws": { "draws": self.fewest_draws.draws, "ids": self.fewest_draws.ids },
+        })
+    }
+}
+
+/// A power as a JSON number, or as a string when it is past what JSON
+/// numbers hold exactly.
+fn power_json(power: u128) -> Value {
+    u64::try_from(power).map_or_else(|_| json!(power.to_string()), |power| json!(power))
+}
+
+/// How many ranges the power distribution is shown in.
+const POWER_BUCKETS: usize = 10;
+
+fn correlation(a: &[u32], b: &[u32]) -> Option<f64> {
+    let n = a.len() as f64;
+    let mean = |xs: &[u32]| xs.iter().map(|&x| f64::from(x)).sum::<f64>() / n;
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (&x, &y) in a.iter().zip(b) {
+        let (dx, dy) = (f64::from(x) - mean_a, f64::from(y) - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+    (var_a > 0.0 && var_b > 0.0).then(|| cov / (var_a * var_b).sqrt())
+}
+
+/// Tables of the draw counts of each colour, the powers, the correlations
+/// and the longest and shortest games.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} games, {} draws", self.games, self.draws)?;
+        let widths: Vec<usize> = self.colours.names().map(|n| n.len().max(6)).collect();
+        let header = |f: &mut fmt::Formatter<'_>, title: &str| {
+            write!(f, "\n{:<12}", title)?;
+            for (name, width) in self.colours.names().zip(&widths) {
+                write!(f, "  {:>width$}", name, width = width)?;
+            }
+            writeln!(f)
+        };
+
+        header(f, "count")?;
+        let rows: BTreeSet<u32> = (self.per_colour.iter())
+            .flat_map(|stats| stats.histogram.keys().copied())
+            .collect();
+        for count in rows {
+            write!(f, "{:<12}", count)?;
+            for (stats, width) in self.per_colour.iter().zip(&widths) {
+                let draws = stats.histogram.get(&count).copied().unwrap_or(0);
+                write!(f, "  {:>width$}", draws, width = width)?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "{:<12}", "mean")?;
+        for (stats, width) in self.per_colour.iter().zip(&widths) {
+            write!(f, "  {:>width$.2}", stats.mean, width = width)?;
+        }
+        writeln!(f)?;
+
+        header(f, "correlation")?;
+        for (name, row) in self.colours.names().zip(&self.correlations) {
+            write!(f, "{:<12}", name)?;
+            for (r, width) in row.iter().zip(&widths) {
+                match r {
+                    Some(r) => write!(f, "  {:>width$.3}", r, width = width)?,
+                    None => write!(f, "  {:>width$}", "-", width = width)?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        if let (Some(min), Some(median), Some(max), Some(mean)) = (
+            self.powers.first(),
+            self.power_quantile(0.5),
+            self.powers.last(),
+            self.power_mean(),
+        ) {
+            writeln!(
+                f,
+                "\npower: min {}, median {}, max {}, mean {:.1}",
+                min, median, max, mean
+            )?;
+            let histogram = self.power_histogram(POWER_BUCKETS);
+            let most = histogram.iter().map(|&(_, n)| n).max().unwrap_or(1);
+            for (from, games) in histogram {
+                let bar = "#".repeat((games as usize * 40).div_ceil(most as usize));
+                writeln!(f, "{:>8}  {:>4}  {}", from, games, bar)?;
+            }
+        }
+        if !self.oversized.is_empty() {
+            let ids: Vec<String> = self.oversized.iter().map(u32::to_string).collect();
+            writeln!(f, "power too large to count: {}", ids.join(" "))?;
+        }
+
+        let ids = |extreme: &Extreme| {
+            let ids: Vec<String> = extreme.ids.iter().map(u32::to_string).collect();
+            let games = if ids.len() == 1 { "game" } else { "games" };
+            format!("{} in {} {}", extreme.draws, games, ids.join(" "))
+        };
+        writeln!(f, "\nmost draws: {}", ids(&self.most_draws))?;
+        writeln!(f, "fewest draws: {}", ids(&self.fewest_draws))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let record = Record::parse(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+             Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+             Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+             Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+             Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n",
+        )
+        .unwrap();
+        let stats = Stats::new(&record);
+        assert_eq!((stats.games, stats.draws), (5, 14));
+        let blue = &stats.per_colour[0];
+        assert_eq!(blue.histogram.len(), 8);
+        assert_eq!((blue.histogram[&0], blue.histogram[&6]), (3, 3));
+        assert_eq!(blue.cubes, 50);
+        assert_eq!(stats.powers, [12, 36, 48, 630, 1560]);
+        assert_eq!(stats.power_quantile(0.5), Some(48));
+        assert!((stats.correlations[1][1].unwrap() - 1.0).abs() < 1e-12);
+        assert_eq!(stats.correlations[0][2], stats.correlations[2][0]);
+        assert_eq!(
+            stats.most_draws,
+            Extreme {
+                draws: 3,
+                ids: vec![1, 2, 3, 4]
+            }
+        );
+        assert_eq!(
+            stats.fewest_draws,
+            Extreme {
+                draws: 2,
+                ids: vec![5]
+            }
+        );
+
+        let buckets = stats.power_histogram(4);
+        assert_eq!(buckets.iter().map(|&(_, n)| n).sum::<u32>(), 5);
+        assert_eq!(buckets[0], (12, 3));
+
+        let json = stats.to_json();
+        assert_eq!(json["colours"][1]["name"], json!("red"));
+        assert_eq!(json["powers"]["max"], json!(1560));
+        assert_eq!(json["fewest_draws"]["ids"], json!([5]));
+        assert!(stats.to_string().contains("\nfewest draws: 2 in game 5\n"));
+
+        assert_eq!(stats.power_histogram(0), [(12, 5)]);
+
+        // Only the counts that were drawn get a row, however large.
+        let stats = Stats::new(&Record::parse("Game 1: 4000000000 red; 1 red\n").unwrap());
+        assert_eq!(stats.per_colour[0].histogram.len(), 2);
+        assert!(stats.to_string().contains("\n4000000000         1\n"));
+
+        // Powers past a u64 are kept, and past a u128 are set aside.
+        let stats = Stats::new(
+            &Record::parse(
+                "Game 1: 4000000000 a, 4000000000 b, 4000000000 c\n\
+                 Game 2: 4000000000 a, 4000000000 b, 4000000000 c, 4000000000 d, 2 e\n\
+                 Game 3: 1 a\n",
+            )
+            .unwrap(),
+        );
+        assert_eq!(stats.powers, [1, 4_000_000_000u128.pow(3)]);
+        assert_eq!(stats.oversized, [2]);
+        assert_eq!(stats.power_histogram(10).len(), 10);
+        assert_eq!(stats.power_histogram(1), [(1, 2)]);
+        let json = stats.to_json();
+        assert_eq!(
+            json["powers"]["max"],
+            json!("64000000000000000000000000000")
+        );
+        assert_eq!(json["powers"]["oversized"], json!([2]));
+        assert!(stats
+            .to_string()
+            .contains("\npower too large to count: 2\n"));
+
+        // A colour that never varies has no correlation.
+        let stats = Stats::new(&Record::parse("Game 1: 1 red, 2 blue; 1 red\n").unwrap());
+        assert_eq!(stats.correlations[0], [None, None]);
+    }
+}
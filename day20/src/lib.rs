@@ -3,55 +3,105 @@ use std::collections::{
     HashMap, VecDeque,
 };
 
+use aoc_core::{math::lcm, parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 20,
+    title: "Pulse Propagation",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day20,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum ModuleType {
     Broadcaster,
     FlipFlop(bool),
     Conjunction(u64),
+    /// A module that is sent pulses but never declared, like `rx`.
+    Output,
 }
 
-fn parse<'a>(input: &'a str) -> Vec<(ModuleType, Vec<usize>)> {
+// Conjunctions keep the state of their inputs in a u64.
+const MAX_MODULES: usize = 64;
+
+fn parse<'a>(input: &'a str) -> Result<Vec<(ModuleType, Vec<usize>)>, ParseError> {
     let mut modules = Vec::new();
     let mut indexes_by_name = HashMap::<&'a _, _>::new();
     let mut next_index = 1;
 
-    let mut get_index = |name: &'a _| match indexes_by_name.entry(name) {
-        Occupied(entry) => *entry.get(),
+    let mut get_index = |name: &'a str| match indexes_by_name.entry(name) {
+        Occupied(entry) => Ok(*entry.get()),
+        Vacant(_) if next_index == MAX_MODULES => Err(ParseError::new(
+            input,
+            name,
+            format!("at most {} modules", MAX_MODULES),
+        )),
         Vacant(entry) => {
             let index = next_index;
             next_index += 1;
             entry.insert(index);
-            index
+            Ok(index)
         }
     };
 
     for line in input.lines() {
-        let (name, destinations) = line.split_once(" -> ").unwrap();
+        let (name, destinations) = parse::split_once(input, line, " -> ")?;
         let (module_type, index) = if name == "broadcaster" {
             (ModuleType::Broadcaster, 0)
+        } else if let Some(name) = name.strip_prefix('%') {
+            (ModuleType::FlipFlop(false), get_index(name)?)
+        } else if let Some(name) = name.strip_prefix('&') {
+            (ModuleType::Conjunction(u64::MAX), get_index(name)?)
         } else {
-            (
-                match &name[..1] {
-                    "%" => ModuleType::FlipFlop(false),
-                    "&" => ModuleType::Conjunction(u64::MAX),
-                    _ => panic!(),
-                },
-                get_index(&name[1..]),
-            )
+            return Err(ParseError::new(input, name, "`%`, `&` or `broadcaster`"));
         };
 
-        let destinations: Vec<_> = destinations.split(", ").map(|x| get_index(x)).collect();
+        let destinations = destinations
+            .split(", ")
+            .map(|name| {
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(ParseError::new(input, name, "a module name"));
+                }
+                get_index(name)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         if index >= modules.len() {
-            modules.resize(index + 1, (ModuleType::Broadcaster, Vec::new()));
+            modules.resize(index + 1, (ModuleType::Output, Vec::new()));
         }
         modules[index] = (module_type, destinations);
     }
-
-    modules
+    modules.resize(
+        next_index.max(modules.len()),
+        (ModuleType::Output, Vec::new()),
+    );
+
+    if !input
+        .lines()
+        .any(|line| line.starts_with("broadcaster -> "))
+    {
+        return Err(ParseError::end(input, "a `broadcaster` module"));
+    }
+    Ok(modules)
 }
 
-fn part1<'a>(input: &'a str) {
-    let mut modules = parse(input);
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let mut modules = parse(input)?;
 
     for i in 0..modules.len() {
         for j in 0..modules[i].1.len() {
@@ -88,6 +138,7 @@ fn part1<'a>(input: &'a str) {
                         Some(*state)
                     }
                 }
+                ModuleType::Output => None,
             } {
                 if resulting_pulse {
                     high_pulses += modules[target].1.len() as u32;
@@ -102,27 +153,14 @@ fn part1<'a>(input: &'a str) {
         }
     }
 
-    let output = low_pulses * high_pulses;
-    println!("Output: {}", output);
-}
-
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    a / gcd(a, b) * b
+    Ok(low_pulses * high_pulses)
 }
 
-fn part2(input: &str) {
-    let modules = parse(input);
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let modules = parse(input)?;
 
     let mut cycler_members = Vec::new();
-    let output = modules[0]
+    modules[0]
         .1
         .iter()
         .map(|&cycler_start| {
@@ -143,6 +181,7 @@ fn part2(input: &str) {
                             exit = false;
                         }
                         ModuleType::Broadcaster => panic!(),
+                        ModuleType::Output => {}
                     }
                 }
             }
@@ -161,14 +200,36 @@ fn part2(input: &str) {
             cycler_members.clear();
             period
         })
-        .reduce(lcm)
-        .unwrap();
-
-    println!("Output: {}", output);
+        .try_fold(1, lcm)
+        .ok_or_else(|| {
+            ParseError::end(
+                input,
+                "cycles whose lengths have a common multiple in a u64",
+            )
+        })
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    part1(&input); // 794930686
-    part2(&input); // 244465191362269
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_modules() {
+        let input = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+        assert_eq!(part1(input).unwrap(), 11687500);
+
+        let err = part1("broadcaster -> \n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 16, "a module name")
+        );
+        let err = part1("broadcaster -> a, \n%a -> b\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 19));
+    }
 }
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use aoc_core::{geometry::Point, grid::Grid, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 21,
+    title: "Step Counter",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day21,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+// Returns the map with the start replaced by a garden plot, and the start as (y, x).
+fn parse(input: &str) -> Result<(Grid<char>, (usize, usize)), ParseError> {
+    let mut map = Grid::parse_tiles(input, ".#S")?;
+    let start = map
+        .position(|&c| c == 'S')
+        .ok_or_else(|| ParseError::end(input, "a start tile `S`"))?;
+    map[start] = '.';
+    Ok((map, (start.y as usize, start.x as usize)))
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let (map, starting_point) = parse(input)?;
+
+    Ok(get_positions_after_steps(&map, starting_point, 64).len())
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let (map, starting_point) = parse(input)?;
+
+    let map_size = map.height();
+    let grid_size = 26501365 / map_size - 1;
+
+    let even_maps_in_grid = (grid_size.div_ceil(2) * 2).pow(2);
+    let odd_maps_in_grid = (grid_size / 2 * 2 + 1).pow(2);
+
+    let odd_points_in_map = count_positions(&map, starting_point, map_size * 2 + 1);
+    let even_points_in_map = count_positions(&map, starting_point, map_size * 2);
+
+    let total_points_fully_in_grid =
+        odd_points_in_map * odd_maps_in_grid + even_points_in_map * even_maps_in_grid;
+
+    let corner_top = count_positions(&map, (map_size - 1, starting_point.1), map_size - 1);
+    let corner_right = count_positions(&map, (starting_point.0, 0), map_size - 1);
+    let corner_bottom = count_positions(&map, (0, starting_point.1), map_size - 1);
+    let corner_left = count_positions(&map, (starting_point.0, map_size - 1), map_size - 1);
+
+    let total_points_in_grid_corners = corner_top + corner_right + corner_bottom + corner_left;
+
+    let small_diag_top_right = count_positions(&map, (map_size - 1, 0), map_size / 2 - 1);
+    let small_diag_bottom_right = count_positions(&map, (0, 0), map_size / 2 - 1);
+    let small_diag_bottom_left = count_positions(&map, (0, map_size - 1), map_size / 2 - 1);
+    let small_diag_top_left = count_positions(&map, (map_size - 1, map_size - 1), map_size / 2 - 1);
+
+    let total_points_in_small_diags = (grid_size + 1)
+        * (small_diag_top_right
+            + small_diag_bottom_right
+            + small_diag_bottom_left
+            + small_diag_top_left);
+
+    let big_diag_top_right = count_positions(&map, (map_size - 1, 0), map_size * 3 / 2 - 1);
+    let big_diag_bottom_right = count_positions(&map, (0, 0), map_size * 3 / 2 - 1);
+    let big_diag_bottom_left = count_positions(&map, (0, map_size - 1), map_size * 3 / 2 - 1);
+    let big_diag_top_left =
+        count_positions(&map, (map_size - 1, map_size - 1), map_size * 3 / 2 - 1);
+
+    let total_points_in_big_diags = grid_size
+        * (big_diag_top_right + big_diag_bottom_right + big_diag_bottom_left + big_diag_top_left);
+
+    let total_points_in_diag = total_points_in_small_diags + total_points_in_big_diags;
+
+    Ok(total_points_fully_in_grid + total_points_in_grid_corners + total_points_in_diag)
+}
+
+fn get_positions_after_steps(map: &Grid<char>, start: (usize, usize), steps: usize) -> HashSet<Point> {
+    let mut positions = HashSet::from([Point::new(start.1 as i32, start.0 as i32)]);
+
+    for _ in 0..steps {
+        positions = positions
+            .iter()
+            .flat_map(|&p| map.neighbors4(p))
+            .filter(|&n| map[n] == '.')
+            .collect();
+    }
+    positions
+}
+
+fn count_positions(map: &Grid<char>, start: (usize, usize), steps: usize) -> usize {
+    get_positions_after_steps(map, start, steps).len()
+}
@@ -1,26 +1,42 @@
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    part1(input);
-    part2(input);
+pub const DAY: Day = Day {
+    number: 22,
+    title: "Sand Slabs",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day22,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
 }
 
-fn part1(input: &str) {
-    let bricks = setup(input);
-    let output = bricks
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let bricks = setup(input)?;
+    Ok(bricks
         .values()
         .filter(|br| br.above.is_empty() || br.above.iter().all(|b| bricks[b].below.len() > 1))
-        .count();
-    println!("Output: {}", output);
+        .count())
 }
 
-fn part2(input: &str) {
-    let bricks = setup(input);
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let bricks = setup(input)?;
     // SUM!! not max
-    let output: usize = bricks.keys().map(|&id| disintegrate(id, &bricks)).sum();
-    println!("Output: {}", output);
+    Ok(bricks.keys().map(|&id| disintegrate(id, &bricks)).sum())
 }
 
 fn disintegrate(start: u32, bricks: &HashMap<u32, Brick>) -> usize {
@@ -42,21 +58,22 @@ fn disintegrate(start: u32, bricks: &HashMap<u32, Brick>) -> usize {
     seen.len().saturating_sub(1)
 }
 
-fn setup(input: &str) -> HashMap<u32, Brick> {
+fn setup(input: &str) -> Result<HashMap<u32, Brick>, ParseError> {
     let mut grid = HashMap::new();
     let mut bricks = HashMap::new();
     // SORT!!
-    for brick in parse(input).sorted_by_key(|b| b.zs) {
+    for brick in parse(input)?.into_iter().sorted_by_key(|b| b.zs) {
         brick.settle(&mut grid, &mut bricks)
     }
-    bricks
+    Ok(bricks)
 }
 
-fn parse(input: &str) -> impl Iterator<Item = Brick> + '_ {
-    input
-        .lines()
-        .enumerate()
-        .map(|(i, line)| Brick::new(i as u32, line))
+fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+    let mut next_id = 0;
+    parse::lines(input, |line| {
+        next_id += 1;
+        Brick::new(next_id - 1, line)
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -70,19 +87,27 @@ struct Brick {
 }
 
 impl Brick {
-    fn new(id: u32, line: &str) -> Self {
-        let mut nums = [0; 6];
-        for (i, s) in line.trim().split(&[',', '~']).enumerate() {
-            nums[i] = s.parse().unwrap();
-        }
-        Self {
+    fn new(id: u32, line: &str) -> Result<Self, ParseError> {
+        let (start, end) = parse::split_once(line, line.trim(), "~")?;
+        let [x1, y1, z1] = Self::coordinates(line, start)?;
+        let [x2, y2, z2] = Self::coordinates(line, end)?;
+        Ok(Self {
             id,
-            xs: (nums[0], nums[3]),
-            ys: (nums[1], nums[4]),
-            zs: (nums[2], nums[5]),
+            xs: (x1, x2),
+            ys: (y1, y2),
+            zs: (z1, z2),
             above: HashSet::new(),
             below: HashSet::new(),
-        }
+        })
+    }
+
+    fn coordinates(line: &str, s: &str) -> Result<[u32; 3], ParseError> {
+        let nums = s
+            .split(',')
+            .map(|n| parse::number(line, n))
+            .collect::<Result<Vec<_>, _>>()?;
+        nums.try_into()
+            .map_err(|_| ParseError::new(line, s, "three coordinates"))
     }
 
     fn settle(
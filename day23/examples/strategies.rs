@@ -0,0 +1,72 @@
+// Compares the different longest path strategies on the puzzle input.
+//
+//     cargo run --release -p day23 --example strategies
+
+use aoc_core::{
+    bench::{self, Stats},
+    Input,
+};
+use day23::{Map, DAY};
+
+fn main() {
+    let input = Input::Bundled.read(&DAY).unwrap();
+    let map = Map::new(&input).unwrap();
+
+    // Print the grid.
+    map.print();
+
+    // Calculate the branches for part 1.
+    let branches = map
+        .map
+        .iter()
+        .filter(|(_, c)| **c != '#')
+        .map(|(p, _)| map.neighbors(&p).len())
+        .filter(|n| *n > 2)
+        .count();
+    println!("p1-branches: {:?}", branches);
+
+    // Part 1 - naive approach, it works because the branching is
+    // smaller here.
+    time("p1", || map.longest_path_frontier());
+
+    // Do the same but with dfs.
+    time("p1", || map.longest_path_dfs());
+
+    // Calculate the branches for part 2.
+    let branches = map
+        .map
+        .iter()
+        .filter(|(_, c)| **c != '#')
+        .map(|(p, _)| map.neighbors_p2(&p).len())
+        .filter(|n| *n > 2)
+        .count();
+    println!("p2-branches: {:?}", branches);
+
+    // The size of the reduced graph.
+    let edges = map.find_branching_edges();
+    println!("nodes: {:?}", edges.len());
+    println!(
+        "edges: {:?}",
+        edges.values().map(|e| e.len()).sum::<usize>()
+    );
+
+    // Part 2 - look for branches in the path and then create a graph
+    // to and from them. This will simplify the graph considerably,
+    // making the search much faster.
+    time("p2", || map.longest_path_branches_frontier());
+
+    // Use dfs instead.
+    time("p2", || map.longest_path_branches_dfs());
+}
+
+/// Prints the result of `f` along with timing statistics over several runs.
+fn time<T: std::fmt::Debug>(name: &str, mut f: impl FnMut() -> T) {
+    let options = bench::Options {
+        warmup: 1,
+        runs: 10,
+        ..Default::default()
+    };
+    let samples = bench::measure(&options, &mut f);
+    println!("{}: {:?}", name, f());
+    println!("    {}", Stats::from_samples(&samples).unwrap());
+}
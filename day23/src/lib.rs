@@ -1,97 +1,75 @@
+use aoc_core::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    Answer, Day, ParseError, Solution,
+};
 use colored::Colorize;
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    ops::Add,
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub const DAY: Day = Day {
+    number: 23,
+    title: "A Long Walk",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day23,
 };
 
-// We'll use a point to track where each position is on the map.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
-}
+pub struct Day23;
 
-// We impl Add here to simplify the code below.
-impl Add for Point {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+impl Solution for Day23 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
     }
-}
-
-// These are our cardinal directions we'll use this to short-circuit
-// slopes.
-static UP: Point = Point::new(0, -1);
-static DOWN: Point = Point::new(0, 1);
-static LEFT: Point = Point::new(-1, 0);
-static RIGHT: Point = Point::new(1, 0);
 
-// In other cases, we iterate over these to find neighbors.
-static DIRECTIONS: [Point; 4] = [UP, DOWN, LEFT, RIGHT];
-
-impl Point {
-    const fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
     }
 
-    // Return all neighbors in all cardinal directions.
-    fn neighbors(&self) -> Vec<Point> {
-        DIRECTIONS.iter().map(|d| *self + *d).collect()
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(Map::new)
     }
 }
 
-struct Map {
-    map: HashMap<Point, char>,
+// Points and the cardinal directions come from aoc-core. We use the
+// directions to short-circuit slopes.
+
+pub struct Map {
+    pub map: Grid<char>,
     start: Point,
     end: Point,
 }
 
 impl Map {
     // Parse the input into our map and find the start and end points.
-    fn new(input: &str) -> Self {
-        // Generate the map.
-        let map = input
-            .lines()
-            .enumerate()
-            .flat_map(|(y, l)| {
-                l.chars()
-                    .enumerate()
-                    .map(|(x, c)| {
-                        (
-                            Point {
-                                x: x as i32,
-                                y: y as i32,
-                            },
-                            c,
-                        )
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect::<HashMap<_, _>>();
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse_tiles(input, "#.^>v<")?;
 
-        // We'll need the max x and y to find the end point. The input
-        // size differs from the test size. If you are just interested
-        // in one or the other, you could hard code the values.
-        let max_x = map.keys().map(|p| p.x).max().unwrap();
-        let max_y = map.keys().map(|p| p.y).max().unwrap();
+        // The path enters in the top row and leaves in the bottom row, one
+        // tile in from the sides.
         let start = Point { x: 1, y: 0 };
         let end = Point {
-            x: max_x - 1,
-            y: max_y,
+            x: map.width() as i32 - 2,
+            y: map.height() as i32 - 1,
         };
-        Self { map, start, end }
+        let first = input.lines().next().unwrap_or_default();
+        let last = input.lines().last().unwrap_or_default();
+        if map.get(start) != Some(&'.') {
+            let at = first.get(1..).unwrap_or(first);
+            return Err(ParseError::new(input, at, "a start tile `.`"));
+        }
+        if end.y == 0 || map[end] != '.' {
+            return Err(ParseError::new(input, &last[last.len() - 2..], "an end tile `.`"));
+        }
+
+        Ok(Self { map, start, end })
     }
 
-    fn neighbors_p2(&self, p: &Point) -> Vec<Point> {
+    pub fn neighbors_p2(&self, p: &Point) -> Vec<Point> {
         // For part 2, we don't care about and arrow indicators. Which
         // means we'll basically just return all cardinal directions
         // that are on the map and not a forest.
         let mut neighbors = Vec::new();
         for d in p.neighbors() {
-            match self.map.get(&d) {
+            match self.map.get(d) {
                 None => continue,
                 Some(c) => match c {
                     '#' => continue,
@@ -102,37 +80,37 @@ impl Map {
         neighbors
     }
 
-    fn neighbors(&self, p: &Point) -> Vec<Point> {
+    pub fn neighbors(&self, p: &Point) -> Vec<Point> {
         // For part 1, there are some rules about what makes a
         // neighbor. First of all, If we are on an arrow, we can only
         // go in that direction.
-        match self.map.get(p).unwrap() {
-            '>' => return vec![*p + RIGHT],
-            '<' => return vec![*p + LEFT],
-            '^' => return vec![*p + UP],
-            'v' => return vec![*p + DOWN],
+        match self.map[*p] {
+            '>' => return vec![p.step(Direction::Right)],
+            '<' => return vec![p.step(Direction::Left)],
+            '^' => return vec![p.step(Direction::Up)],
+            'v' => return vec![p.step(Direction::Down)],
             _ => {}
         }
 
         // Otherwise, we'll return all cardinal directions that are on
         // the map and not a forest. I don't use the Point::neighbors
-        // because we want to know the delta to determine if we can go
-        // into a slope.
+        // because we want to know the direction to determine if we can
+        // go into a slope.
         let mut neighbors = Vec::new();
-        for d in DIRECTIONS.iter() {
-            let p = *p + *d;
-            match self.map.get(&p) {
+        for d in Direction::ALL {
+            let p = p.step(d);
+            match self.map.get(p) {
                 None => continue,
-                Some(c) => match (c, d.x, d.y) {
+                Some(c) => match (c, d) {
                     // We can't go back up a slope, so I wrote it sort
                     // of the opposite as one normally would. The 5
                     // conditions below are the invalid neighbors and
                     // then all others would be valid.
-                    ('#', _, _) => continue,
-                    ('>', -1, 0) => continue,
-                    ('<', 1, 0) => continue,
-                    ('^', 0, 1) => continue,
-                    ('v', 0, -1) => continue,
+                    ('#', _) => continue,
+                    ('>', Direction::Left) => continue,
+                    ('<', Direction::Right) => continue,
+                    ('^', Direction::Down) => continue,
+                    ('v', Direction::Up) => continue,
                     _ => neighbors.push(p),
                 },
             }
@@ -140,7 +118,7 @@ impl Map {
         neighbors
     }
 
-    fn longest_path_frontier(&self) -> usize {
+    pub fn longest_path_frontier(&self) -> usize {
         // When we reach the end, we'll want to keep track of
         // it. We'll return the largest of the values.
         let mut ends = Vec::new();
@@ -202,20 +180,18 @@ impl Map {
         seen.remove(&p);
     }
 
-    fn longest_path_dfs(&self) -> usize {
+    pub fn longest_path_dfs(&self) -> usize {
         let mut ends = Vec::new();
         let mut seen = HashSet::new();
         self.dfs(self.start, &mut seen, 0, &mut ends);
         *ends.iter().max().unwrap()
     }
 
-    fn print(&self) {
-        let max_x = self.map.keys().map(|p| p.x).max().unwrap();
-        let max_y = self.map.keys().map(|p| p.y).max().unwrap();
-        for y in 0..=max_y {
-            for x in 0..=max_x {
+    pub fn print(&self) {
+        for y in 0..self.map.height() as i32 {
+            for x in 0..self.map.width() as i32 {
                 let p = Point { x, y };
-                match self.map.get(&p).unwrap() {
+                match self.map[p] {
                     '#' => print!("#"),
                     '>' => print!("{}", ">".green()),
                     '<' => print!("{}", "<".green()),
@@ -239,15 +215,15 @@ impl Map {
         }
     }
 
-    fn find_branching_edges(&self) -> HashMap<Point, Vec<(Point, usize)>> {
+    pub fn find_branching_edges(&self) -> HashMap<Point, Vec<(Point, usize)>> {
         // Relabel the map with the count of neighbors.
         let map = self
             .map
             .iter()
             .filter(|(_, c)| **c != '#')
             .map(|(p, _)| {
-                let n = self.neighbors_p2(p).len();
-                (*p, n)
+                let n = self.neighbors_p2(&p).len();
+                (p, n)
             })
             .collect::<HashMap<_, _>>();
 
@@ -295,14 +271,9 @@ impl Map {
         edges
     }
 
-    fn longest_path_branches_frontier(&self) -> usize {
+    pub fn longest_path_branches_frontier(&self) -> usize {
         // Find the reduced graph.
         let edges = self.find_branching_edges();
-        println!("nodes: {:?}", edges.len());
-        println!(
-            "edges: {:?}",
-            edges.values().map(|e| e.len()).sum::<usize>()
-        );
 
         // Now we can do the longest path again, but with our reduced
         // graph. I won't comment this one again because it's
@@ -332,7 +303,7 @@ impl Map {
         *ends.iter().max().unwrap()
     }
 
-    fn longest_path_branches_dfs(&self) -> usize {
+    pub fn longest_path_branches_dfs(&self) -> usize {
         // Find the reduced graph.
         let edges = self.find_branching_edges();
 
@@ -375,53 +346,10 @@ impl Map {
     }
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let map = Map::new(&input);
-
-    // Print the grid.
-    map.print();
-
-    // Calculate the branches for part 1.
-    let branches = map
-        .map
-        .iter()
-        .filter(|(_, c)| **c != '#')
-        .map(|(p, _)| map.neighbors(p).len())
-        .filter(|n| *n > 2)
-        .count();
-    println!("p1-branches: {:?}", branches);
-
-    // Part 1 - naive approach, it works because the branching is
-    // smaller here.
-    let now = std::time::Instant::now();
-    let p1 = map.longest_path_frontier();
-    println!("p1: {:?} ({:?})", p1, now.elapsed());
-
-    // Do the same but with dfs.
-    let now = std::time::Instant::now();
-    let p1 = map.longest_path_dfs();
-    println!("p1: {:?} ({:?})", p1, now.elapsed());
-
-    // Calculate the branches for part 2.
-    let branches = map
-        .map
-        .iter()
-        .filter(|(_, c)| **c != '#')
-        .map(|(p, _)| map.neighbors_p2(p).len())
-        .filter(|n| *n > 2)
-        .count();
-    println!("p2-branches: {:?}", branches);
-
-    // Part 2 - look for branches in the path and then create a graph
-    // to and from them. This will simplify the graph considerably,
-    // making the search much faster.
-    let now = std::time::Instant::now();
-    let p2 = map.longest_path_branches_frontier();
-    println!("p2: {:?} ({:?})", p2, now.elapsed());
-
-    // Use dfs instead.
-    let now = std::time::Instant::now();
-    let p2 = map.longest_path_branches_dfs();
-    println!("p2: {:?} ({:?})", p2, now.elapsed());
-}
\ No newline at end of file
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(Map::new(input)?.longest_path_dfs())
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(Map::new(input)?.longest_path_branches_dfs())
+}
@@ -1,5 +1,29 @@
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
 use itertools::Itertools;
-use std::{fmt::Display, io::Read, str::FromStr};
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: Day = Day {
+    number: 24,
+    title: "Never Tell Me The Odds",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day24,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse_input)
+    }
+}
 
 const EPSILON: f64 = 0.0001;
 
@@ -11,23 +35,17 @@ struct Vec3 {
 }
 
 impl FromStr for Vec3 {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut it = s.trim().split(", ");
-        let x = it.next().ok_or("no x".to_string())?.trim();
-        let y = it.next().ok_or("no y".to_string())?.trim();
-        let z = it.next().ok_or("no z".to_string())?.trim();
-
-        let x = x
-            .parse::<f64>()
-            .map_err(|_| format!("could not parse x: {}", x))?;
-        let y = y
-            .parse::<f64>()
-            .map_err(|_| "could not parse y".to_string())?;
-        let z = z
-            .parse::<f64>()
-            .map_err(|_| "could not parse y".to_string())?;
+        let x = it.next().unwrap_or(s).trim();
+        let y = it.next().ok_or_else(|| ParseError::end(s, "a y coordinate"))?.trim();
+        let z = it.next().ok_or_else(|| ParseError::end(s, "a z coordinate"))?.trim();
+
+        let x = parse::number(s, x)?;
+        let y = parse::number(s, y)?;
+        let z = parse::number(s, z)?;
 
         Ok(Self { x, y, z })
     }
@@ -46,15 +64,17 @@ struct Hailstone {
 }
 
 impl FromStr for Hailstone {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let mut it = line.trim().split('@');
-        let start = it.next().ok_or("no start".to_string())?;
-        let velocity = it.next().ok_or("no velocity".to_string())?;
+        let (start, velocity) = parse::split_once(line, line.trim(), "@")?;
 
-        let start = start.parse::<Vec3>()?;
-        let velocity = velocity.parse::<Vec3>()?;
+        let start = start
+            .parse::<Vec3>()
+            .map_err(|err| err.within(line, start))?;
+        let velocity = velocity
+            .parse::<Vec3>()
+            .map_err(|err| err.within(line, velocity))?;
 
         Ok(Self { start, velocity })
     }
@@ -109,19 +129,16 @@ impl Display for Hailstone {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Hailstone> {
-    let line_count = input.trim().lines().count();
-    let mut result = Vec::with_capacity(line_count);
-
-    for line in input.trim().lines() {
-        result.push(line.parse::<Hailstone>().unwrap())
-    }
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    parse::lines(input.trim_end(), str::parse)
+}
 
-    result
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    count_intersections(input, 200000000000000., 400000000000000.)
 }
 
-fn part1(input: &str, min: f64, max: f64) -> usize {
-    let hailstones = parse_input(input);
+fn count_intersections(input: &str, min: f64, max: f64) -> Result<usize, ParseError> {
+    let hailstones = parse_input(input)?;
 
     let mut result = 0;
 
@@ -142,7 +159,7 @@ fn part1(input: &str, min: f64, max: f64) -> usize {
         }
     }
 
-    result
+    Ok(result)
 }
 
 fn solve_part2(a: Hailstone, b: Hailstone, vx: f64, vy: f64, vz: f64) -> Option<(f64, f64, Vec3)> {
@@ -197,8 +214,11 @@ fn solve_part2(a: Hailstone, b: Hailstone, vx: f64, vy: f64, vz: f64) -> Option<
         ))
     }
 }
-fn part2(input: &str) -> usize {
-    let hailstones = parse_input(input);
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let hailstones = parse_input(input)?;
+    if hailstones.len() < 2 {
+        return Err(ParseError::end(input, "at least two hailstones"));
+    }
 
     let a = hailstones[0];
     let b = hailstones[1];
@@ -239,9 +259,7 @@ fn part2(input: &str) -> usize {
                         continue;
                     }
 
-                    for i in 2..hailstones.len() {
-                        let c = hailstones[i];
-
+                    for &c in &hailstones[2..] {
                         let Hailstone {
                             start:
                                 Vec3 {
@@ -266,7 +284,7 @@ fn part2(input: &str) -> usize {
                         }
                     }
 
-                    return px as usize + py as usize + pz as usize;
+                    return Ok(px as usize + py as usize + pz as usize);
                 }
             }
         }
@@ -275,18 +293,6 @@ fn part2(input: &str) -> usize {
     panic!("found no solution");
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input = include_str!("../input.txt");
-
-    println!(
-        "Part 1: {}",
-        part1(&input, 200000000000000., 400000000000000.)
-    );
-    println!("Part 2: {}", part2(&input));
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,17 +306,30 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let expected = 2;
-        let actual = part1(EXAMPLE, 7., 27.);
+        let expected = Ok(2);
+        let actual = count_intersections(EXAMPLE, 7., 27.);
 
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_part2() {
-        let expected = 47;
+        let expected = Ok(47);
         let actual = part2(EXAMPLE);
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, x, -2\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 18: expected a number, found `x`");
+
+        let err = parse_input("19, 13, 30 @ -2,  1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 20));
+        assert_eq!(err.expected, "a z coordinate");
+
+        let err = parse_input("19, 13, 30 -2, 1, -2\n").unwrap_err();
+        assert_eq!(err.expected, "`@`");
+    }
 }
\ No newline at end of file
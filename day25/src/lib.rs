@@ -1,9 +1,21 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    part1(input); // 589036
+use aoc_core::{parse, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 25,
+    title: "Snowverload",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"),
+    solution: &Day25,
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
 }
 
 fn maximum_adjacency_search(
@@ -120,14 +132,22 @@ fn min_cut(mut g: HashMap<String, HashMap<String, i64>>) -> usize {
     best_partition
 }
 
-pub fn part1(input: &str) {
+pub fn part1(input: &str) -> Result<usize, ParseError> {
     let mut graph: HashMap<String, HashMap<String, i64>> = HashMap::new();
     for line in input.lines() {
-        let trimmed = line.trim().replace(':', "");
+        let trimmed = line.trim();
         if trimmed.is_empty() {
             continue;
         }
-        let parts = trimmed.split_whitespace().collect::<Vec<_>>();
+        let (name, connected) = parse::split_once(input, trimmed, ":")?;
+        if connected.trim().is_empty() {
+            let at = &trimmed[trimmed.len()..];
+            return Err(ParseError::new(input, at, "a connected component"));
+        }
+        let parts = [name]
+            .into_iter()
+            .chain(connected.split_whitespace())
+            .collect::<Vec<_>>();
         for i in 1..parts.len() {
             graph
                 .entry(parts[0].to_string())
@@ -151,7 +171,10 @@ pub fn part1(input: &str) {
                 });
         }
     }
+    if graph.is_empty() {
+        return Err(ParseError::end(input, "a component"));
+    }
     let nodes = graph.len();
     let min_cut = min_cut(graph);
-    println!("Output: {}", min_cut * (nodes - min_cut));
+    Ok(min_cut * (nodes - min_cut))
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+// Prints a schematic with the numbers that were counted as parts, the ones
+// that were not, and the symbols a rule applied to marked, then how each of
+// those symbols was worked out. Colours are used on a terminal unless
+// NO_COLOR is set or --plain is given, which marks the rows with letters.
+//
+//     cargo run --release -p day3 --example render -- day3/example.txt
+//     cargo run --release -p day3 --example render -- --plain --rules rules.txt
+
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    process::ExitCode,
+};
+
+use aoc_core::Input;
+use day3::{Rules, Schematic, Style, DAY};
+
+fn main() -> ExitCode {
+    let usage = "usage: render [--plain] [--rules path] [input path|-]";
+    let mut plain = false;
+    let mut rules = Rules::puzzle();
+    let mut input = Input::Bundled;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--plain" => plain = true,
+            "--rules" => {
+                let Some(path) = args.next() else {
+                    eprintln!("{}", usage);
+                    return ExitCode::FAILURE;
+                };
+                let config = match fs::read_to_string(&path) {
+                    Ok(config) => config,
+                    Err(err) => {
+                        eprintln!("error: cannot read {}: {}", path, err);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                rules = match Rules::parse(&config) {
+                    Ok(rules) => rules,
+                    Err(err) => {
+                        eprintln!("error: {}: {}", path, err);
+                        return ExitCode::FAILURE;
+                    }
+                };
+            }
+            _ if arg.starts_with("--") => {
+                eprintln!("{}", usage);
+                return ExitCode::FAILURE;
+            }
+            _ => input = Input::from_arg(&arg),
+        }
+    }
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let schematic = match Schematic::parse_with(&input, &rules) {
+        Ok(schematic) => schematic,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let colour = !plain && io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    let style = if colour {
+        Style::Colour
+    } else {
+        Style::Markers
+    };
+    print!("{}", schematic.annotate(&rules, style));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,77 @@
+// Answers a schematic variant with its own rules: which characters are
+// symbols, which cells they reach, and what each symbol contributes given how
+// many parts it touches.
+//
+//     cargo run --release -p day3 --example rules -- rules.txt day3/input1.txt
+//
+// where rules.txt holds lines like
+//
+//     symbols *#
+//     neighbourhood radius 2
+//     # >= 3 sum
+//     * == 2 product
+
+use std::{fs, process::ExitCode};
+
+use aoc_core::Input;
+use day3::{Rules, Schematic, DAY};
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        eprintln!("usage: rules <rules path> [input path|-]");
+        return ExitCode::FAILURE;
+    };
+    let rules = match fs::read_to_string(&path) {
+        Ok(config) => match Rules::parse(&config) {
+            Ok(rules) => rules,
+            Err(err) => {
+                eprintln!("error: {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(err) => {
+            eprintln!("error: cannot read {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = args
+        .next()
+        .map_or(Input::Bundled, |arg| Input::from_arg(&arg));
+
+    let input = match input.read(&DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let schematic = match Schematic::parse_with(&input, &rules) {
+        Ok(schematic) => schematic,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Totals that do not fit in a `usize` are shown as too large.
+    let show = |total: Option<usize>| total.map_or("too large".to_string(), |t| t.to_string());
+    let mut totals = vec![(0, Some(0usize)); rules.rules.len()];
+    for (_, rule, value) in schematic.apply(&rules) {
+        let (symbols, total) = &mut totals[rule];
+        *symbols += 1;
+        *total = total
+            .zip(value)
+            .and_then(|(total, value)| total.checked_add(value));
+    }
+    for (rule, (symbols, total)) in rules.rules.iter().zip(totals) {
+        println!(
+            "{:<16} {:>5} symbols  {}",
+            rule.to_string(),
+            symbols,
+            show(total)
+        );
+    }
+    println!("parts: {}", show(schematic.part_sum()));
+    println!("total: {}", show(schematic.total(&rules)));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,398 @@
+//! Editing a schematic cell by cell with its totals kept up to date.
+
+use std::{error::Error, fmt};
+
+use aoc_core::{geometry::Point, grid::Grid, ParseError};
+
+use crate::{Number, Rules, Schematic};
+
+/// Why an edit was refused. The schematic is left as it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditError {
+    Outside(Point),
+    /// The edit would join digits into a number too large to add up.
+    TooLarge(Point),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::Outside(p) => write!(f, "({}, {}) is outside the schematic", p.x, p.y),
+            EditError::TooLarge(p) => {
+                write!(f, "the number at ({}, {}) would be too large", p.x, p.y)
+            }
+        }
+    }
+}
+
+impl Error for EditError {}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    number: Number,
+    part: bool,
+}
+
+/// A schematic that can be edited one cell at a time, keeping the sum of its
+/// parts and the total its rules give up to date. An edit only looks at the
+/// numbers it changes and the cells they reach, however large the schematic.
+#[derive(Debug, Clone)]
+pub struct Editor {
+    rules: Rules,
+    grid: Grid<char>,
+    /// The slot of the number covering each cell.
+    number_at: Grid<Option<usize>>,
+    /// Numbers by slot. The slots of removed numbers are reused.
+    numbers: Vec<Option<Entry>>,
+    free: Vec<usize>,
+    /// What the symbol in each cell contributes, where a rule applies. The
+    /// inner `None` is a contribution too large for a `usize`.
+    contributions: Grid<Option<Option<usize>>>,
+    /// The sums are kept exactly, and only have to fit in a `usize` when
+    /// asked for.
+    part_sum: u128,
+    total: u128,
+    /// How many symbols contribute too much for a `usize`.
+    too_large: usize,
+}
+
+impl Editor {
+    pub fn new(input: &str, mut rules: Rules) -> Result<Self, ParseError> {
+        let schematic = Schematic::parse_with(input, &rules)?;
+        let grid = schematic.grid().clone();
+        // Edits never change the grid's size, so neither does the clamp.
+        rules.neighbourhood = rules.neighbourhood.clamp(grid.width(), grid.height());
+        let mut number_at = Grid::new(grid.width(), grid.height(), None);
+        let numbers = (schematic.numbers().iter().enumerate())
+            .map(|(i, &number)| {
+                for cell in number.cells() {
+                    number_at[cell] = Some(i);
+                }
+                let part = schematic.is_part(i);
+                Some(Entry { number, part })
+            })
+            .collect();
+        let mut contributions = Grid::new(grid.width(), grid.height(), None);
+        let (mut total, mut too_large) = (0, 0);
+        for (symbol, _, value) in schematic.apply(&rules) {
+            contributions[schematic.symbols()[symbol].at] = Some(value);
+            match value {
+                Some(value) => total += value as u128,
+                None => too_large += 1,
+            }
+        }
+        Ok(Self {
+            part_sum: schematic.parts().map(|part| part.value as u128).sum(),
+            total,
+            too_large,
+            rules,
+            grid,
+            number_at,
+            numbers,
+            free: Vec::new(),
+            contributions,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// The sum of the numbers next to a symbol, or `None` if it does not fit
+    /// in a `usize`.
+    pub fn part_sum(&self) -> Option<usize> {
+        usize::try_from(self.part_sum).ok()
+    }
+
+    /// What every symbol contributes by the rules, added up, or `None` if it
+    /// does not fit in a `usize`.
+    pub fn total(&self) -> Option<usize> {
+        let total = usize::try_from(self.total).ok();
+        total.filter(|_| self.too_large == 0)
+    }
+
+    /// The numbers in the schematic, in no particular order.
+    pub fn numbers(&self) -> impl Iterator<Item = &Number> + '_ {
+        self.numbers.iter().flatten().map(|entry| &entry.number)
+    }
+
+    /// Sets the cell at `at` to `c` and brings the totals up to date,
+    /// returning what the cell held before.
+    pub fn set(&mut self, at: Point, c: char) -> Result<char, EditError> {
+        let old = *self.grid.get(at).ok_or(EditError::Outside(at))?;
+        if old == c {
+            return Ok(old);
+        }
+
+        // The numbers the edit can change: the one under the cell and the
+        // ones it could join or split, on either side.
+        let mut removed: Vec<usize> = (-1..=1)
+            .filter_map(|dx| self.number_at(at + Point::new(dx, 0)))
+            .collect();
+        removed.dedup();
+        let row = at.y as usize;
+        let (mut from, mut to) = (at.x as usize, at.x as usize + 1);
+        for &slot in &removed {
+            let number = &self.numbers[slot].as_ref().expect("slot is in use").number;
+            from = from.min(number.start);
+            to = to.max(number.end);
+        }
+
+        // Find the numbers that take their place before changing anything, so
+        // that a number too large to keep leaves the schematic alone.
+        let mut added = Vec::new();
+        let mut x = from;
+        while x < to {
+            let digit = |x: usize| {
+                let cell = if x == at.x as usize {
+                    c
+                } else {
+                    self.grid.row(row)[x]
+                };
+                cell.is_ascii_digit().then_some(cell)
+            };
+            if digit(x).is_none() {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            let mut digits = String::new();
+            while let Some(d) = (x < to).then(|| digit(x)).flatten() {
+                digits.push(d);
+                x += 1;
+            }
+            let value = digits
+                .parse()
+                .map_err(|_| EditError::TooLarge(Point::new(start as i32, at.y)))?;
+            added.push(Number {
+                value,
+                row,
+                start,
+                end: x,
+            });
+        }
+
+        let mut symbols = Vec::new();
+        for slot in removed {
+            let entry = self.numbers[slot].take().expect("slot is in use");
+            if entry.part {
+                self.part_sum -= entry.number.value as u128;
+            }
+            for cell in entry.number.cells() {
+                self.number_at[cell] = None;
+                symbols.extend(self.reached(cell));
+            }
+            self.free.push(slot);
+        }
+        self.grid[at] = c;
+        for number in added {
+            let slot = self.free.pop().unwrap_or_else(|| {
+                self.numbers.push(None);
+                self.numbers.len() - 1
+            });
+            for cell in number.cells() {
+                self.number_at[cell] = Some(slot);
+                symbols.extend(self.reached(cell));
+            }
+            self.numbers[slot] = Some(Entry {
+                number,
+                part: false,
+            });
+            self.update_part(slot);
+        }
+
+        // A symbol coming or going changes which numbers around it are parts.
+        if self.rules.symbols.contains(old) || self.rules.symbols.contains(c) {
+            symbols.push(at);
+            let near: Vec<usize> = self
+                .reached(at)
+                .filter_map(|cell| self.number_at(cell))
+                .collect();
+            for slot in near {
+                self.update_part(slot);
+            }
+        }
+
+        symbols.sort_unstable();
+        symbols.dedup();
+        for symbol in symbols {
+            let contribution = self.contribution(symbol);
+            let before = std::mem::replace(&mut self.contributions[symbol], contribution);
+            match before {
+                Some(Some(value)) => self.total -= value as u128,
+                Some(None) => self.too_large -= 1,
+                None => {}
+            }
+            match contribution {
+                Some(Some(value)) => self.total += value as u128,
+                Some(None) => self.too_large += 1,
+                None => {}
+            }
+        }
+        Ok(old)
+    }
+
+    fn number_at(&self, p: Point) -> Option<usize> {
+        self.number_at.get(p).copied().flatten()
+    }
+
+    /// The cells in the grid that the rules' neighbourhood reaches from `p`.
+    /// Reaching is symmetric, so these are also the cells that reach `p`.
+    fn reached(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let neighbourhood = self.rules.neighbourhood;
+        let rows = neighbourhood.rows() as i32;
+        let (width, height) = (self.grid.width() as i32, self.grid.height() as i32);
+        (-rows.min(p.y)..=rows.min(height - 1 - p.y)).flat_map(move |dy| {
+            let reach = neighbourhood.reach(i64::from(dy)).unwrap_or(-1) as i32;
+            (-reach.min(p.x)..=reach.min(width - 1 - p.x)).map(move |dx| p + Point::new(dx, dy))
+        })
+    }
+
+    /// Works out again whether the number in `slot` is a part.
+    fn update_part(&mut self, slot: usize) {
+        let entry = self.numbers[slot].as_ref().expect("slot is in use");
+        let part = entry.number.cells().any(|cell| {
+            self.reached(cell)
+                .any(|near| self.rules.symbols.contains(self.grid[near]))
+        });
+        let (value, was) = (entry.number.value as u128, entry.part);
+        match (was, part) {
+            (false, true) => self.part_sum += value,
+            (true, false) => self.part_sum -= value,
+            _ => {}
+        }
+        self.numbers[slot].as_mut().expect("slot is in use").part = part;
+    }
+
+    /// What the cell at `p` contributes by the rules, if it is a symbol one
+    /// applies to, and `None` within that if it does not fit in a `usize`.
+    fn contribution(&self, p: Point) -> Option<Option<usize>> {
+        let symbol = self.grid[p];
+        if !self.rules.symbols.contains(symbol) {
+            return None;
+        }
+        let mut parts: Vec<usize> = self
+            .reached(p)
+            .filter_map(|cell| self.number_at(cell))
+            .collect();
+        parts.sort_unstable();
+        parts.dedup();
+        let rule = (self.rules.rules.iter()).find(|rule| rule.applies(symbol, parts.len()))?;
+        let values = parts.iter().map(|&slot| {
+            let entry = self.numbers[slot].as_ref().expect("slot is in use");
+            entry.number.value
+        });
+        Some(rule.reducer.reduce(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the editor's totals against parsing its grid from scratch.
+    fn check(editor: &Editor) {
+        let text: String = editor
+            .grid()
+            .rows()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let schematic = Schematic::parse_with(&text, &editor.rules).unwrap();
+        assert_eq!(editor.part_sum(), schematic.part_sum(), "{}", text);
+        assert_eq!(editor.total(), schematic.total(&editor.rules), "{}", text);
+        let mut numbers: Vec<_> = editor.numbers().copied().collect();
+        numbers.sort_by_key(|n| (n.row, n.start));
+        assert_eq!(numbers, schematic.numbers(), "{}", text);
+    }
+
+    #[test]
+    fn test_edits() {
+        let mut editor = Editor::new(include_str!("../example.txt"), Rules::puzzle()).unwrap();
+        assert_eq!(
+            (editor.part_sum(), editor.total()),
+            (Some(4361), Some(467835))
+        );
+
+        // Turning the * after 617 into a digit leaves 6170 next to no symbol.
+        assert_eq!(editor.set(Point::new(3, 4), '0'), Ok('*'));
+        assert_eq!(editor.part_sum(), Some(4361 - 617));
+        check(&editor);
+        assert_eq!(editor.set(Point::new(3, 4), '*'), Ok('0'));
+        assert_eq!(
+            (editor.part_sum(), editor.total()),
+            (Some(4361), Some(467835))
+        );
+
+        assert_eq!(
+            editor.set(Point::new(10, 0), '#'),
+            Err(EditError::Outside(Point::new(10, 0)))
+        );
+        let mut editor = Editor::new("9999999999999999999*\n", Rules::puzzle()).unwrap();
+        assert_eq!(
+            editor.set(Point::new(19, 0), '9'),
+            Err(EditError::TooLarge(Point::new(0, 0)))
+        );
+        assert_eq!(editor.grid()[Point::new(19, 0)], '*');
+        assert_eq!(editor.part_sum(), Some(9999999999999999999));
+        check(&editor);
+    }
+
+    #[test]
+    fn test_large_totals() {
+        let input = "9999999999999999999*\n9999999999999999999*\n";
+        let rules = Rules::parse("any >= 0 sum\n").unwrap();
+        let mut editor = Editor::new(input, rules).unwrap();
+        assert_eq!((editor.part_sum(), editor.total()), (None, None));
+        check(&editor);
+        editor.set(Point::new(0, 1), '.').unwrap();
+        assert_eq!(
+            editor.part_sum(),
+            Some(9999999999999999999 + 999999999999999999)
+        );
+        // Both symbols still touch both numbers.
+        assert_eq!(editor.total(), None);
+        check(&editor);
+        editor.set(Point::new(19, 1), '.').unwrap();
+        assert_eq!(editor.total(), editor.part_sum());
+        check(&editor);
+
+        // Every symbol multiplies every number in the example together.
+        let rules = Rules::parse("neighbourhood radius 1000\nany >= 0 product\n").unwrap();
+        let mut editor = Editor::new(include_str!("../example.txt"), rules).unwrap();
+        assert_eq!(
+            (editor.part_sum(), editor.total()),
+            (Some(4361 + 114 + 58), None)
+        );
+        check(&editor);
+        // A 0 anywhere makes every product 0.
+        editor.set(Point::new(9, 9), '0').unwrap();
+        assert_eq!(editor.total(), Some(0));
+        check(&editor);
+    }
+
+    #[test]
+    fn test_random_edits() {
+        let configs = [
+            "any >= 0 sum\n",
+            "neighbourhood 4\n* == 2 product\n# >= 1 max\n",
+            "neighbourhood radius 2\nsymbols *#\n* == 2 product\nany >= 3 sum\n",
+            "neighbourhood radius 4000000000\nany >= 0 max\n",
+            "neighbourhood radius 4000000000\nany >= 0 product\n",
+        ];
+        let cells = ['.', '.', '.', '1', '2', '7', '9', '*', '#', '+'];
+        for config in configs {
+            let rules = Rules::parse(config).unwrap();
+            let mut editor = Editor::new(include_str!("../example.txt"), rules).unwrap();
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            for _ in 0..2000 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let at = Point::new((state % 10) as i32, (state / 10 % 10) as i32);
+                let c = cells[(state / 100 % cells.len() as u64) as usize];
+                editor.set(at, c).unwrap();
+                check(&editor);
+            }
+        }
+    }
+}
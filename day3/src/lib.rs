@@ -0,0 +1,48 @@
+mod edit;
+mod render;
+mod rules;
+mod schematic;
+
+use aoc_core::{Answer, Day, ParseError, Solution};
+
+pub use edit::{EditError, Editor};
+pub use render::{Annotated, Style};
+pub use rules::{Count, Neighbourhood, Reducer, Rule, Rules, Symbols};
+pub use schematic::{Number, Schematic, Symbol};
+
+pub const DAY: Day = Day {
+    number: 3,
+    title: "Gear Ratios",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day3,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(Schematic::parse)
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    schematic
+        .part_sum()
+        .ok_or_else(|| ParseError::end(input, "parts that add up to a usize"))
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let schematic = Schematic::parse(input)?;
+    schematic
+        .total(&Rules::puzzle())
+        .ok_or_else(|| ParseError::end(input, "gear ratios that add up to a usize"))
+}
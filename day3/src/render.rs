@@ -0,0 +1,196 @@
+//! Printing a schematic with what was counted marked on it.
+
+use std::fmt;
+
+use aoc_core::{geometry::Point, grid::Grid};
+
+use crate::{Reducer, Rules, Schematic};
+
+/// How the marks are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// ANSI colours: parts in green, ignored numbers dimmed and symbols a rule
+    /// applied to in bold yellow.
+    Colour,
+    /// A line of markers under each row: `p` under parts, `x` under ignored
+    /// numbers and `g` under symbols a rule applied to. For logs and anything
+    /// else without colour.
+    Markers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    None,
+    Part,
+    Ignored,
+    Matched,
+}
+
+impl Mark {
+    fn colour(self) -> &'static str {
+        match self {
+            Mark::None => "\x1b[0m",
+            Mark::Part => "\x1b[32m",
+            Mark::Ignored => "\x1b[2m",
+            Mark::Matched => "\x1b[1;33m",
+        }
+    }
+
+    fn marker(self) -> char {
+        match self {
+            Mark::None => ' ',
+            Mark::Part => 'p',
+            Mark::Ignored => 'x',
+            Mark::Matched => 'g',
+        }
+    }
+}
+
+/// A schematic marked up by `rules`, then every symbol a rule applied to
+/// with how its value was worked out, and the totals.
+pub struct Annotated<'a> {
+    schematic: &'a Schematic,
+    rules: &'a Rules,
+    style: Style,
+}
+
+impl Schematic {
+    /// The schematic marked up by `rules`, which it should have been parsed
+    /// with.
+    pub fn annotate<'a>(&'a self, rules: &'a Rules, style: Style) -> Annotated<'a> {
+        Annotated {
+            schematic: self,
+            rules,
+            style,
+        }
+    }
+}
+
+impl fmt::Display for Annotated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let schematic = self.schematic;
+        let grid = schematic.grid();
+        let matches: Vec<_> = schematic.apply(self.rules).collect();
+        let mut marks = Grid::new(grid.width(), grid.height(), Mark::None);
+        for (i, number) in schematic.numbers().iter().enumerate() {
+            let mark = if schematic.is_part(i) {
+                Mark::Part
+            } else {
+                Mark::Ignored
+            };
+            for cell in number.cells() {
+                marks[cell] = mark;
+            }
+        }
+        for &(symbol, _, _) in &matches {
+            marks[schematic.symbols()[symbol].at] = Mark::Matched;
+        }
+
+        for (row, row_marks) in grid.rows().zip(marks.rows()) {
+            match self.style {
+                Style::Colour => {
+                    let mut current = Mark::None;
+                    for (&c, &mark) in row.iter().zip(row_marks) {
+                        if mark != current {
+                            if current != Mark::None {
+                                write!(f, "{}", Mark::None.colour())?;
+                            }
+                            if mark != Mark::None {
+                                write!(f, "{}", mark.colour())?;
+                            }
+                            current = mark;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    if current != Mark::None {
+                        write!(f, "{}", Mark::None.colour())?;
+                    }
+                    writeln!(f)?;
+                }
+                Style::Markers => {
+                    writeln!(f, "{}", row.iter().collect::<String>())?;
+                    if row_marks.iter().any(|&mark| mark != Mark::None) {
+                        let markers: String = row_marks.iter().map(|m| m.marker()).collect();
+                        writeln!(f, "{}", markers.trim_end())?;
+                    }
+                }
+            }
+        }
+
+        writeln!(f)?;
+        for &(symbol, rule, value) in &matches {
+            let Point { x, y } = schematic.symbols()[symbol].at;
+            let values: Vec<String> = schematic
+                .parts_of(symbol)
+                .iter()
+                .map(|&i| schematic.numbers()[i].value.to_string())
+                .collect();
+            let working = match self.rules.rules[rule].reducer {
+                Reducer::Sum => values.join(" + "),
+                Reducer::Product => values.join(" * "),
+                Reducer::Max => format!("max({})", values.join(", ")),
+            };
+            writeln!(
+                f,
+                "{} at row {}, column {}: {} = {}",
+                schematic.symbols()[symbol].c,
+                y + 1,
+                x + 1,
+                working,
+                too_large(value)
+            )?;
+        }
+
+        let parts = schematic.parts().count();
+        let ignored = schematic.numbers().len() - parts;
+        writeln!(
+            f,
+            "parts: {} counted, {} ignored, sum {}",
+            parts,
+            ignored,
+            too_large(schematic.part_sum())
+        )?;
+        writeln!(
+            f,
+            "matched symbols: {}, sum {}",
+            matches.len(),
+            too_large(schematic.total(self.rules))
+        )
+    }
+}
+
+/// A value, or `too large` where it did not fit in a `usize`.
+fn too_large(value: Option<usize>) -> String {
+    value.map_or_else(|| "too large".to_string(), |value| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotate() {
+        let schematic = Schematic::parse("467..114..\n...*......\n..35..633.\n").unwrap();
+        let rules = Rules::puzzle();
+        let text = schematic.annotate(&rules, Style::Markers).to_string();
+        assert_eq!(
+            text,
+            "\
+467..114..
+ppp  xxx
+...*......
+   g
+..35..633.
+  pp  xxx
+
+* at row 2, column 4: 467 * 35 = 16345
+parts: 2 counted, 2 ignored, sum 502
+matched symbols: 1, sum 16345
+"
+        );
+
+        let text = schematic.annotate(&rules, Style::Colour).to_string();
+        assert!(text.starts_with("\x1b[32m467\x1b[0m..\x1b[2m114\x1b[0m..\n"));
+        assert!(text.contains("\n...\x1b[1;33m*\x1b[0m......\n"));
+    }
+}
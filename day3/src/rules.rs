@@ -0,0 +1,343 @@
+use std::fmt;
+
+use aoc_core::{parse, ParseError};
+
+/// Which characters are symbols. Digits and `.` never are.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Symbols {
+    #[default]
+    Any,
+    Only(Vec<char>),
+}
+
+impl Symbols {
+    pub fn contains(&self, c: char) -> bool {
+        if c.is_ascii_digit() || c == '.' {
+            return false;
+        }
+        match self {
+            Symbols::Any => true,
+            Symbols::Only(symbols) => symbols.contains(&c),
+        }
+    }
+}
+
+/// Which cells around a symbol it reaches. A number is next to the symbol
+/// when any of its digits is in a reached cell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cells sharing a side with the symbol.
+    Four,
+    /// The eight cells sharing a side or a corner, as in the puzzle.
+    #[default]
+    Eight,
+    /// Every cell at most this many rows and columns away.
+    Radius(u32),
+}
+
+impl Neighbourhood {
+    /// How many columns either side of the symbol are reached on the row
+    /// `dy` rows away, or `None` if none are.
+    pub fn reach(self, dy: i64) -> Option<i64> {
+        let rows = self.rows();
+        match self {
+            _ if dy.abs() > rows => None,
+            Neighbourhood::Four => Some(rows - dy.abs()),
+            Neighbourhood::Eight | Neighbourhood::Radius(_) => Some(rows),
+        }
+    }
+
+    /// The same neighbourhood with a radius no larger than a `width` by
+    /// `height` grid, which any larger radius reaches all of anyway.
+    pub fn clamp(self, width: usize, height: usize) -> Self {
+        match self {
+            Neighbourhood::Radius(r) => {
+                let most = u32::try_from(width.max(height)).unwrap_or(u32::MAX);
+                Neighbourhood::Radius(r.min(most))
+            }
+            _ => self,
+        }
+    }
+
+    /// How many rows above and below the symbol are reached.
+    pub fn rows(self) -> i64 {
+        match self {
+            Neighbourhood::Four | Neighbourhood::Eight => 1,
+            Neighbourhood::Radius(r) => i64::from(r),
+        }
+    }
+}
+
+/// How many parts a symbol must touch for a rule to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Count {
+    pub fn matches(self, parts: usize) -> bool {
+        match self {
+            Count::Exactly(n) => parts == n,
+            Count::AtLeast(n) => parts >= n,
+            Count::AtMost(n) => parts <= n,
+        }
+    }
+}
+
+/// How a rule combines the parts a symbol touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reducer {
+    Sum,
+    Product,
+    Max,
+}
+
+impl Reducer {
+    /// Combines `values`, or `None` if the result does not fit in a `usize`.
+    /// No values make 0, or 1 for a product.
+    pub fn reduce(self, mut values: impl Iterator<Item = usize>) -> Option<usize> {
+        match self {
+            Reducer::Sum => values.try_fold(0, usize::checked_add),
+            Reducer::Product => {
+                // A 0 makes the product 0 even if the others overflow.
+                let values: Vec<usize> = values.collect();
+                if values.contains(&0) {
+                    return Some(0);
+                }
+                values.into_iter().try_fold(1, usize::checked_mul)
+            }
+            Reducer::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+/// What a symbol contributes when it touches the right number of parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// The symbol the rule is for, or `None` for every symbol.
+    pub symbol: Option<char>,
+    pub count: Count,
+    pub reducer: Reducer,
+}
+
+impl Rule {
+    /// The puzzle's gear: a `*` next to exactly two parts, worth their
+    /// product.
+    pub const GEAR: Rule = Rule {
+        symbol: Some('*'),
+        count: Count::Exactly(2),
+        reducer: Reducer::Product,
+    };
+
+    pub fn applies(&self, symbol: char, parts: usize) -> bool {
+        self.symbol.is_none_or(|s| s == symbol) && self.count.matches(parts)
+    }
+}
+
+/// Which characters are symbols, which cells they reach, and what each
+/// symbol contributes. A symbol contributes by the first rule that applies to
+/// it, or not at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub symbols: Symbols,
+    pub neighbourhood: Neighbourhood,
+    pub rules: Vec<Rule>,
+}
+
+impl Rules {
+    /// The puzzle's rules: every character but digits and `.` is a symbol, and
+    /// only gears contribute.
+    pub fn puzzle() -> Self {
+        Self {
+            symbols: Symbols::Any,
+            neighbourhood: Neighbourhood::Eight,
+            rules: vec![Rule::GEAR],
+        }
+    }
+
+    /// Reads rules from lines like `* == 2 product`, `# >= 3 sum` or
+    /// `any <= 1 max`, with an optional `symbols *#$` line limiting which
+    /// characters are symbols and an optional `neighbourhood 4`,
+    /// `neighbourhood 8` or `neighbourhood radius 2` line saying which cells
+    /// they reach. Blank lines and lines starting with `//` are ignored.
+    pub fn parse(config: &str) -> Result<Self, ParseError> {
+        let mut symbols = Symbols::Any;
+        let mut neighbourhood = Neighbourhood::Eight;
+        let mut rules = Vec::new();
+        for line in config.lines() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            if let Some(only) = line.strip_prefix("symbols ") {
+                symbols = Symbols::Only(only.chars().filter(|c| !c.is_whitespace()).collect());
+                continue;
+            }
+            if let Some(shape) = line.strip_prefix("neighbourhood ") {
+                neighbourhood = match shape {
+                    "4" => Neighbourhood::Four,
+                    "8" => Neighbourhood::Eight,
+                    _ => match shape.strip_prefix("radius ") {
+                        Some(radius) => Neighbourhood::Radius(parse::number(config, radius)?),
+                        None => {
+                            let expected = "`4`, `8` or `radius`";
+                            return Err(ParseError::new(config, shape, expected));
+                        }
+                    },
+                };
+                continue;
+            }
+            rules.push(Rule::parse(config, line)?);
+        }
+        if rules.is_empty() {
+            return Err(ParseError::end(config, "a rule like `* == 2 product`"));
+        }
+        Ok(Self {
+            symbols,
+            neighbourhood,
+            rules,
+        })
+    }
+}
+
+impl Rule {
+    fn parse(src: &str, line: &str) -> Result<Self, ParseError> {
+        let (symbol, rest) = parse::split_once(src, line, " ")?;
+        let symbol = match symbol {
+            "any" => None,
+            _ => {
+                let mut chars = symbol.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if Symbols::Any.contains(c) => Some(c),
+                    _ => return Err(ParseError::new(src, symbol, "a symbol or `any`")),
+                }
+            }
+        };
+        let (op, rest) = parse::split_once(src, rest, " ")?;
+        let (n, reducer) = parse::split_once(src, rest, " ")?;
+        let n = parse::number(src, n)?;
+        let count = match op {
+            "==" => Count::Exactly(n),
+            ">=" => Count::AtLeast(n),
+            "<=" => Count::AtMost(n),
+            _ => return Err(ParseError::new(src, op, "one of `==` `>=` `<=`")),
+        };
+        let reducer = match reducer {
+            "sum" => Reducer::Sum,
+            "product" => Reducer::Product,
+            "max" => Reducer::Max,
+            _ => {
+                return Err(ParseError::new(
+                    src,
+                    reducer,
+                    "one of `sum` `product` `max`",
+                ))
+            }
+        };
+        Ok(Self {
+            symbol,
+            count,
+            reducer,
+        })
+    }
+}
+
+/// Writes the rule the way `Rules::parse` reads it.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.symbol {
+            Some(symbol) => write!(f, "{}", symbol)?,
+            None => write!(f, "any")?,
+        }
+        match self.count {
+            Count::Exactly(n) => write!(f, " == {}", n)?,
+            Count::AtLeast(n) => write!(f, " >= {}", n)?,
+            Count::AtMost(n) => write!(f, " <= {}", n)?,
+        }
+        let reducer = match self.reducer {
+            Reducer::Sum => "sum",
+            Reducer::Product => "product",
+            Reducer::Max => "max",
+        };
+        write!(f, " {}", reducer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schematic;
+
+    #[test]
+    fn test_rules() {
+        let rules = Rules::parse(
+            "// three-part hashes add up, other hashes count their largest part\n\
+             symbols *#\n\
+             # >= 3 sum\n\
+             # >= 1 max\n\
+             * == 2 product\n",
+        )
+        .unwrap();
+        assert_eq!(rules.symbols, Symbols::Only(vec!['*', '#']));
+        assert_eq!(rules.rules[2], Rule::GEAR);
+        assert_eq!(rules.rules[0].to_string(), "# >= 3 sum");
+
+        let schematic = Schematic::parse_with("1.2.3\n.#..#\n4....\n..7+.\n", &rules).unwrap();
+        // The `+` is not a symbol, so 7 is not a part.
+        assert_eq!(schematic.parts().map(|n| n.value).sum::<usize>(), 10);
+        let matches: Vec<_> = schematic.apply(&rules).collect();
+        assert_eq!(matches, [(0, 0, Some(1 + 2 + 4)), (1, 1, Some(3))]);
+        assert_eq!(schematic.total(&rules), Some(10));
+        assert_eq!(schematic.total(&Rules::puzzle()), Some(0));
+
+        let err = Rules::parse("* == 2 product\n** >= 1 sum\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Rules::parse("* > 2 product\n").unwrap_err();
+        assert_eq!(err.expected, "one of `==` `>=` `<=`");
+        assert!(Rules::parse("symbols *\n").is_err());
+        let err = Rules::parse("neighbourhood 6\n* == 2 product\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (15, "`4`, `8` or `radius`")
+        );
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        let input = "\
+...12.....
+....*...5.
+...3......
+999...77..
+......4...
+";
+        let near = |config: &str| {
+            let rules = Rules::parse(&format!("{}\nany >= 0 sum\n", config)).unwrap();
+            let schematic = Schematic::parse_with(input, &rules).unwrap();
+            schematic.total(&rules).unwrap()
+        };
+        // Only the 12 touches a side; the 3 is on a corner.
+        assert_eq!(near("neighbourhood 4"), 12);
+        assert_eq!(near("neighbourhood 8"), 12 + 3);
+        // Two away reaches the 999 by its last digit and the 77 by its first.
+        assert_eq!(near("neighbourhood radius 2"), 12 + 3 + 999 + 77);
+        assert_eq!(near("neighbourhood radius 3"), 12 + 3 + 999 + 77 + 4);
+        assert_eq!(near("neighbourhood radius 4"), 12 + 3 + 999 + 77 + 4 + 5);
+        assert_eq!(near("neighbourhood radius 0"), 0);
+
+        assert_eq!(Neighbourhood::Four.reach(1), Some(0));
+        assert_eq!(Neighbourhood::Radius(2).reach(-2), Some(2));
+        assert_eq!(Neighbourhood::Eight.reach(2), None);
+        assert_eq!(
+            near("neighbourhood radius 4000000000"),
+            near("neighbourhood radius 10")
+        );
+        assert_eq!(
+            Neighbourhood::Radius(50).clamp(10, 5),
+            Neighbourhood::Radius(10)
+        );
+        assert_eq!(Neighbourhood::Four.clamp(0, 0), Neighbourhood::Four);
+    }
+}
@@ -0,0 +1,254 @@
+use aoc_core::{geometry::Point, grid::Grid, parse, ParseError};
+
+use crate::{Neighbourhood, Reducer, Rule, Rules};
+
+/// A run of digits in the schematic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: usize,
+    pub row: usize,
+    /// The columns the digits cover, from `start` up to but not including
+    /// `end`.
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Number {
+    /// The cells the digits cover.
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start..self.end).map(|x| Point::new(x as i32, self.row as i32))
+    }
+}
+
+/// A character the schematic's `Symbols` count as a symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub c: char,
+    pub at: Point,
+}
+
+/// An engine schematic with its numbers and symbols found once, and which
+/// numbers touch which symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// The index of the number covering each cell.
+    number_at: Grid<Option<usize>>,
+    /// For each symbol, the numbers next to it, in the order they appear.
+    parts: Vec<Vec<usize>>,
+    /// For each number, the symbols next to it, in the order they appear.
+    symbols_of: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    /// Parses a schematic with the puzzle's symbols and neighbourhood.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Rules::puzzle())
+    }
+
+    /// Parses a schematic with the symbols and neighbourhood of `rules`.
+    pub fn parse_with(input: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "a character", Some)?;
+        let mut numbers = Vec::new();
+        let mut number_at = Grid::new(grid.width(), grid.height(), None);
+        for (row, line) in input.lines().enumerate() {
+            let mut digits = line.char_indices().enumerate().peekable();
+            while let Some((start, (from, c))) = digits.next() {
+                if !c.is_ascii_digit() {
+                    continue;
+                }
+                let mut end = (start + 1, from + 1);
+                while let Some(&(x, (i, c))) = digits.peek() {
+                    if !c.is_ascii_digit() {
+                        break;
+                    }
+                    end = (x + 1, i + 1);
+                    digits.next();
+                }
+                let number = Number {
+                    value: parse::number(input, &line[from..end.1])?,
+                    row,
+                    start,
+                    end: end.0,
+                };
+                for cell in number.cells() {
+                    number_at[cell] = Some(numbers.len());
+                }
+                numbers.push(number);
+            }
+        }
+
+        let neighbourhood = rules.neighbourhood.clamp(grid.width(), grid.height());
+        let symbols: Vec<Symbol> = grid
+            .iter()
+            .filter(|&(_, &c)| rules.symbols.contains(c))
+            .map(|(at, &c)| Symbol { c, at })
+            .collect();
+        let mut symbols_of = vec![Vec::new(); numbers.len()];
+        let parts = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                let parts = numbers_near(&numbers, symbol.at, neighbourhood);
+                for &part in &parts {
+                    symbols_of[part].push(i);
+                }
+                parts
+            })
+            .collect();
+
+        Ok(Self {
+            grid,
+            numbers,
+            symbols,
+            number_at,
+            parts,
+            symbols_of,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Every number, row by row.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Every symbol, row by row.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The index of the number with a digit at `p`, if any.
+    pub fn number_at(&self, p: Point) -> Option<usize> {
+        self.number_at.get(p).copied().flatten()
+    }
+
+    /// The indexes of the numbers next to symbol `symbol`.
+    pub fn parts_of(&self, symbol: usize) -> &[usize] {
+        &self.parts[symbol]
+    }
+
+    /// The indexes of the symbols next to number `number`.
+    pub fn symbols_of(&self, number: usize) -> &[usize] {
+        &self.symbols_of[number]
+    }
+
+    /// Whether number `number` is next to any symbol.
+    pub fn is_part(&self, number: usize) -> bool {
+        !self.symbols_of[number].is_empty()
+    }
+
+    /// The numbers next to at least one symbol, each once.
+    pub fn parts(&self) -> impl Iterator<Item = &Number> + '_ {
+        (0..self.numbers.len())
+            .filter(|&i| self.is_part(i))
+            .map(|i| &self.numbers[i])
+    }
+
+    /// The sum of the parts, or `None` if it does not fit in a `usize`.
+    pub fn part_sum(&self) -> Option<usize> {
+        self.parts()
+            .try_fold(0usize, |sum, part| sum.checked_add(part.value))
+    }
+
+    /// The `*` symbols next to exactly two numbers, with the product of the
+    /// two, or `None` where it does not fit in a `usize`.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, Option<usize>)> + '_ {
+        self.symbols.iter().enumerate().filter_map(|(i, symbol)| {
+            let gear = Rule::GEAR.applies(symbol.c, self.parts[i].len());
+            gear.then(|| (symbol, self.contribution(i, Rule::GEAR.reducer)))
+        })
+    }
+
+    /// Every symbol some rule applies to, as the symbol's index, the index of
+    /// the first rule that applies and what the symbol contributes by it,
+    /// which is `None` where it does not fit in a `usize`. Which characters
+    /// are symbols was settled when the schematic was parsed, so parse it
+    /// with `rules.symbols`.
+    pub fn apply<'a>(
+        &'a self,
+        rules: &'a Rules,
+    ) -> impl Iterator<Item = (usize, usize, Option<usize>)> + 'a {
+        self.symbols.iter().enumerate().filter_map(|(i, symbol)| {
+            let parts = self.parts[i].len();
+            let (rule, r) =
+                (rules.rules.iter().enumerate()).find(|(_, rule)| rule.applies(symbol.c, parts))?;
+            Some((i, rule, self.contribution(i, r.reducer)))
+        })
+    }
+
+    /// What every symbol contributes by `rules`, added up, or `None` if it
+    /// does not fit in a `usize`.
+    pub fn total(&self, rules: &Rules) -> Option<usize> {
+        self.apply(rules)
+            .try_fold(0usize, |total, (_, _, value)| total.checked_add(value?))
+    }
+
+    fn contribution(&self, symbol: usize, reducer: Reducer) -> Option<usize> {
+        let values = self.parts[symbol].iter().map(|&i| self.numbers[i].value);
+        reducer.reduce(values)
+    }
+}
+
+/// The indexes of the numbers with a digit in the cells `neighbourhood`
+/// reaches from `at`, in order. `numbers` must be in order too, so that each
+/// row's numbers can be found by binary search and checked by whether their
+/// spans overlap the reached columns.
+fn numbers_near(numbers: &[Number], at: Point, neighbourhood: Neighbourhood) -> Vec<usize> {
+    let mut near = Vec::new();
+    for dy in -neighbourhood.rows()..=neighbourhood.rows() {
+        let (Some(reach), row) = (neighbourhood.reach(dy), i64::from(at.y) + dy) else {
+            continue;
+        };
+        let (left, right) = (i64::from(at.x) - reach, i64::from(at.x) + reach);
+        let key = |n: &Number| (n.row as i64, n.end as i64);
+        let first = numbers.partition_point(|n| key(n) <= (row, left));
+        near.extend(
+            (first..numbers.len())
+                .take_while(|&i| numbers[i].row as i64 == row && numbers[i].start as i64 <= right),
+        );
+    }
+    near
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacency() {
+        let schematic = Schematic::parse("*12.\n..3#\n4...\n").unwrap();
+        let values: Vec<_> = schematic.numbers().iter().map(|n| n.value).collect();
+        assert_eq!(values, [12, 3, 4]);
+        assert_eq!(
+            schematic.numbers()[0],
+            Number {
+                value: 12,
+                row: 0,
+                start: 1,
+                end: 3
+            }
+        );
+        // A symbol in the corner, and a number next to two symbols.
+        assert_eq!(schematic.parts_of(0), [0]);
+        assert_eq!(schematic.parts_of(1), [0, 1]);
+        assert_eq!(schematic.symbols_of(0), [0, 1]);
+        assert!(!schematic.is_part(2));
+        assert_eq!(schematic.number_at(Point::new(2, 0)), Some(0));
+        assert_eq!(schematic.number_at(Point::new(-1, 0)), None);
+        assert_eq!(schematic.parts().map(|n| n.value).sum::<usize>(), 15);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // Too large for a usize.
+        let err = Schematic::parse(".*99999999999999999999999\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert!(Schematic::parse("...\n..\n").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_core::{parse, ParseError};
+
+/// A set of numbers as one bit per number, so that counting the numbers two
+/// sets share is an `and` and a popcount.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberSet {
+    /// Numbers below 128, which covers the puzzle's two-digit numbers.
+    Small(u128),
+    /// Numbers below `WIDE_LIMIT`, 64 to a word. The set takes a bit for
+    /// every number up to its largest.
+    Wide(Vec<u64>),
+    /// Sets with a number too large for a bit per number, sorted and without
+    /// repeats.
+    Sparse(Vec<u32>),
+}
+
+/// The numbers a `Wide` set can hold, which keeps it to 8 KiB.
+const WIDE_LIMIT: u32 = 1 << 16;
+
+impl NumberSet {
+    pub fn new(numbers: &[u32]) -> Self {
+        match numbers.iter().max() {
+            Some(&max) if max >= WIDE_LIMIT => {
+                let mut numbers = numbers.to_vec();
+                numbers.sort_unstable();
+                numbers.dedup();
+                NumberSet::Sparse(numbers)
+            }
+            Some(&max) if max >= 128 => {
+                let mut words = vec![0; max as usize / 64 + 1];
+                for &n in numbers {
+                    words[n as usize / 64] |= 1 << (n % 64);
+                }
+                NumberSet::Wide(words)
+            }
+            _ => NumberSet::Small(numbers.iter().fold(0, |bits, &n| bits | 1 << n)),
+        }
+    }
+
+    pub fn contains(&self, n: u32) -> bool {
+        self.word(n as usize / 64) & 1 << (n % 64) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        if let NumberSet::Sparse(numbers) = self {
+            return numbers.len();
+        }
+        (0..self.words())
+            .map(|i| self.word(i).count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many numbers are in both sets.
+    pub fn common(&self, other: &NumberSet) -> usize {
+        match (self, other) {
+            (NumberSet::Small(a), NumberSet::Small(b)) => (a & b).count_ones() as usize,
+            (NumberSet::Sparse(numbers), set) | (set, NumberSet::Sparse(numbers)) => {
+                numbers.iter().filter(|&&n| set.contains(n)).count()
+            }
+            _ => (0..self.words().min(other.words()))
+                .map(|i| (self.word(i) & other.word(i)).count_ones() as usize)
+                .sum(),
+        }
+    }
+
+    fn words(&self) -> usize {
+        match self {
+            NumberSet::Small(_) => 2,
+            NumberSet::Wide(words) => words.len(),
+            NumberSet::Sparse(numbers) => numbers.last().map_or(0, |&n| n as usize / 64 + 1),
+        }
+    }
+
+    /// The bits for numbers `64 * i` up to `64 * i + 63`.
+    fn word(&self, i: usize) -> u64 {
+        match self {
+            NumberSet::Small(bits) => bits.checked_shr(64 * i as u32).unwrap_or(0) as u64,
+            NumberSet::Wide(words) => words.get(i).copied().unwrap_or(0),
+            NumberSet::Sparse(numbers) => {
+                let from = numbers.partition_point(|&n| (n as usize) < 64 * i);
+                (numbers[from..].iter())
+                    .take_while(|&&n| n as usize / 64 == i)
+                    .fold(0, |bits, &n| bits | 1 << (n % 64))
+            }
+        }
+    }
+}
+
+/// A scratchcard. A number written twice on the same side counts once, so
+/// having a winning number twice is one match rather than two. The puzzle's
+/// cards never repeat a number, so their answers are the same either way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: usize,
+    pub winning: NumberSet,
+    pub mine: NumberSet,
+}
+
+impl Card {
+    /// Parses a line like `Card 1: 41 48 83 | 83 86  6`.
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (card, numbers) = parse::split_once(line, line, ": ")?;
+        let card = parse::strip_prefix(line, card, "Card")?;
+        let id = parse::number(line, card.trim_start())?;
+        let (winning, mine) = parse::split_once(line, numbers, " | ")?;
+        Ok(Self {
+            id,
+            winning: NumberSet::new(&parse::number_list(line, winning)?),
+            mine: NumberSet::new(&parse::number_list(line, mine)?),
+        })
+    }
+
+    /// Parses one card per line.
+    pub fn parse_all(input: &str) -> Result<Vec<Self>, ParseError> {
+        parse::lines(input, Self::parse)
+    }
+
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.common(&self.mine)
+    }
+
+    /// 1 point for the first match, doubled for every match after it. `None`
+    /// past 128 matches, where the points no longer fit in a `u128`.
+    pub fn points(&self) -> Option<u128> {
+        match self.matches() {
+            0 => Some(0),
+            n => 1u128.checked_shl(u32::try_from(n - 1).ok()?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_set() {
+        let small = NumberSet::new(&[1, 64, 99, 99]);
+        assert_eq!(small, NumberSet::Small(1 << 1 | 1 << 64 | 1 << 99));
+        assert_eq!(small.len(), 3);
+        assert!(small.contains(64) && !small.contains(65) && !small.contains(1000));
+
+        let wide = NumberSet::new(&[1, 99, 128, 1000]);
+        assert!(matches!(wide, NumberSet::Wide(ref words) if words.len() == 16));
+        assert!(wide.contains(1000) && !wide.contains(64));
+        assert_eq!(small.common(&wide), 2);
+        assert_eq!(wide.common(&small), 2);
+        assert_eq!(wide.common(&wide), 4);
+        assert!(NumberSet::new(&[]).is_empty());
+
+        let sparse = NumberSet::new(&[u32::MAX, 64, 70000, 64]);
+        assert_eq!(sparse, NumberSet::Sparse(vec![64, 70000, u32::MAX]));
+        assert_eq!(sparse.len(), 3);
+        assert!(sparse.contains(u32::MAX) && sparse.contains(64) && !sparse.contains(65));
+        assert_eq!(sparse.common(&small), 1);
+        assert_eq!(wide.common(&sparse), 0);
+        assert_eq!(sparse.common(&NumberSet::new(&[70000, 99999])), 1);
+    }
+
+    #[test]
+    fn test_card() {
+        let card = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(card.id, 1);
+        assert_eq!((card.matches(), card.points()), (4, Some(8)));
+        let card = Card::parse("Card 2: 5 200 | 200 5 5 7").unwrap();
+        assert_eq!((card.matches(), card.points()), (2, Some(2)));
+
+        let numbers = |n: u32| (1..=n).map(|i| i.to_string()).collect::<Vec<_>>().join(" ");
+        let card = |n| Card::parse(&format!("Card 1: {0} | {0}", numbers(n))).unwrap();
+        assert_eq!(card(0).points(), Some(0));
+        assert_eq!(card(128).points(), Some(1 << 127));
+        assert_eq!(card(129).points(), None);
+        assert_eq!(card(200).points(), None);
+
+        let err = Card::parse_all("Card 1: 1 | 1\nCard 2: 1 x | 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 11));
+    }
+}
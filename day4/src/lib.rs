@@ -0,0 +1,93 @@
+mod card;
+
+use aoc_core::{Answer, Day, ParseError, Solution};
+
+pub use card::{Card, NumberSet};
+
+pub const DAY: Day = Day {
+    number: 4,
+    title: "Scratchcards",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day4,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(Card::parse_all)
+    }
+}
+
+/// The total points. It is an error for them to be too large for an
+/// `Answer`, reported at the card that takes them past it.
+pub fn part1(input: &str) -> Result<i128, ParseError> {
+    let cards = Card::parse_all(input)?;
+    let mut sum: i128 = 0;
+    for (card, line) in cards.iter().zip(input.lines()) {
+        let points = card.points().and_then(|points| i128::try_from(points).ok());
+        sum = points
+            .and_then(|points| sum.checked_add(points))
+            .ok_or_else(|| ParseError::new(input, line, "points that add up to below 2^127"))?;
+    }
+    Ok(sum)
+}
+
+/// How many cards are held in the end. It is an error for there to be too
+/// many for an `Answer`, reported at the card whose copies take them past it.
+pub fn part2(input: &str) -> Result<i128, ParseError> {
+    let cards = Card::parse_all(input)?;
+    let lines: Vec<&str> = input.lines().collect();
+    let too_many = |i: usize| ParseError::new(input, lines[i], "fewer than 2^127 cards");
+    // Every card is held once, plus the copies won by the cards before it.
+    let mut copies: Vec<i128> = vec![1; cards.len()];
+    let mut total: i128 = 0;
+    for (i, card) in cards.iter().enumerate() {
+        total = total.checked_add(copies[i]).ok_or_else(|| too_many(i))?;
+        let won = (i + 1..=i + card.matches()).take_while(|&j| j < cards.len());
+        for j in won {
+            copies[j] = copies[j]
+                .checked_add(copies[i])
+                .ok_or_else(|| too_many(j))?;
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_answers() {
+        // Every card matches all of its numbers.
+        let cards = |count: usize, matches: u32| {
+            let numbers: Vec<String> = (1..=matches).map(|n| n.to_string()).collect();
+            let numbers = numbers.join(" ");
+            (1..=count)
+                .map(|id| format!("Card {}: {} | {}\n", id, numbers, numbers))
+                .collect::<String>()
+        };
+        let input = cards(80, 80);
+        assert_eq!(part1(&input).unwrap(), 80 << 79);
+        assert_eq!(part2(&input).unwrap(), (1 << 80) - 1);
+
+        let err = part1(&cards(2, 129)).unwrap_err();
+        assert_eq!(err.line, 1);
+        let input = cards(200, 80);
+        assert_eq!(part1(&input).unwrap(), 200 << 79);
+        let err = part2(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (128, "fewer than 2^127 cards")
+        );
+    }
+}
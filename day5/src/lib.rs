@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 5,
+    title: "If You Give A Seed A Fertilizer",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day5,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+/// The seeds and the translation tables, whose entries are
+/// `[destination, source, length]`.
+type Almanac = (Vec<u64>, Vec<Vec<[u64; 3]>>);
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut blocks = parse::blocks(input);
+    let seeds = blocks
+        .next()
+        .ok_or_else(|| ParseError::end(input, "`seeds:`"))?;
+    let seeds = parse::strip_prefix(input, seeds, "seeds:")?;
+    let seed_numbers = parse::number_list(input, seeds)?;
+    if seed_numbers.is_empty() {
+        return Err(ParseError::new(input, seeds, "a seed"));
+    }
+
+    let translation_tables = blocks
+        .map(|block| {
+            let mut lines = block.lines();
+            let header = lines.next().unwrap_or_default();
+            if !header.ends_with(" map:") {
+                return Err(ParseError::new(input, header, "a map header"));
+            }
+            lines
+                .map(|line| {
+                    let range = parse::number_list(input, line)?;
+                    range
+                        .try_into()
+                        .map_err(|_| ParseError::new(input, line, "three numbers"))
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((seed_numbers, translation_tables))
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    let (mut seed_numbers, translation_tables) = parse(input)?;
+
+    for seed in seed_numbers.iter_mut() {
+        for table in translation_tables.iter() {
+            let range = table
+                .iter()
+                .find(|range| *seed >= range[1] && *seed < range[1] + range[2]);
+            if let Some(range) = range {
+                *seed = range[0] + (*seed - range[1]);
+            }
+        }
+    }
+
+    Ok(*seed_numbers.iter().min().unwrap())
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let (seed_numbers, translation_tables) = parse(input)?;
+    if seed_numbers.len() % 2 != 0 {
+        let seeds = input.lines().next().unwrap_or_default();
+        return Err(ParseError::new(input, &seeds[seeds.len()..], "a range length"));
+    }
+
+    let mut current_ranges = HashSet::new();
+
+    for chunk in seed_numbers.chunks(2) {
+        current_ranges.insert((chunk[0], chunk[0] + chunk[1] - 1));
+    }
+
+    for table in translation_tables.iter() {
+        let mut table_splits = HashSet::new();
+
+        for range in current_ranges.iter() {
+            let mut range_splits = HashSet::new();
+            range_splits.insert(*range);
+
+            for line in table.iter() {
+                let mut line_splits = HashSet::new();
+
+                for range_split in range_splits.iter() {
+                    if range_split.1 > line[1] && line[1] + line[2] >= range_split.1
+                        || line[1] + line[2] < range_split.1 && range_split.0 < line[1] + line[2]
+                    {
+                        if range_split.0 < line[1] {
+                            line_splits.insert((range_split.0, line[1] - 1));
+                        }
+
+                        let overlap = (
+                            range_split.0.max(line[1]),
+                            range_split.1.min(line[1] + line[2] - 1),
+                        );
+                        table_splits.insert((
+                            line[0] + (overlap.0 - line[1]),
+                            line[0] + (overlap.1 - line[1]),
+                        ));
+
+                        if range_split.1 > line[1] + line[2] - 1 {
+                            line_splits.insert((line[1] + line[2] - 1, range_split.1));
+                        }
+                    } else {
+                        line_splits.insert(*range_split);
+                    }
+                }
+
+                range_splits = line_splits.clone();
+                line_splits.clear();
+            }
+
+            table_splits.extend(range_splits.clone());
+            range_splits.clear();
+        }
+
+        current_ranges = table_splits.clone();
+        table_splits.clear();
+    }
+
+    Ok(current_ranges.iter().map(|x| x.0).min().unwrap())
+}
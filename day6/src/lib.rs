@@ -0,0 +1,82 @@
+use aoc_core::{parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 6,
+    title: "Wait For It",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day6,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+/// The text after the `Time:` and `Distance:` labels.
+fn parse(input: &str) -> Result<(&str, &str), ParseError> {
+    let end = &input[input.len()..];
+    let mut lines = input.lines();
+    let time = parse::strip_prefix(input, lines.next().unwrap_or(end), "Time:")?;
+    let distance = parse::strip_prefix(input, lines.next().unwrap_or(end), "Distance:")?;
+    Ok((time, distance))
+}
+
+/// Reads the numbers in `s` as a single number, ignoring the spaces between them.
+fn joined(input: &str, s: &str) -> Result<u64, ParseError> {
+    parse::number_list::<u64>(input, s)?;
+    s.split_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new(input, s.trim_start(), "a number"))
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    let (time_line, distance_line) = parse(input)?;
+    let time = parse::number_list::<u64>(input, time_line)?;
+    let distance = parse::number_list::<u64>(input, distance_line)?;
+    if distance.len() < time.len() {
+        let end = &distance_line[distance_line.len()..];
+        return Err(ParseError::new(input, end, "a distance for every race"));
+    }
+
+    let mut product = 1u64;
+    for (t, d) in time.into_iter().zip(distance) {
+        let mut num_ways_to_win = 0;
+        for i in 0..t {
+            let remaining_time = t - i;
+
+            if i * remaining_time > d {
+                num_ways_to_win += 1;
+            }
+        }
+        product *= num_ways_to_win;
+    }
+    Ok(product)
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let (time, distance) = parse(input)?;
+    let time = joined(input, time)?;
+    let distance = joined(input, distance)?;
+
+    let mut num_ways_to_win = 0;
+    for i in 0..time {
+        let remaining_time = time - i;
+
+        if i * remaining_time > distance {
+            num_ways_to_win += 1;
+        }
+    }
+    Ok(num_ways_to_win)
+}
@@ -1,5 +1,30 @@
 use std::collections::HashMap;
 
+use aoc_core::{parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 7,
+    title: "Camel Cards",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day7,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(Input::<Card>::parse)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Eq)]
 pub(crate) struct Hand<CardType> {
     cards: [CardType; 5],
@@ -27,18 +52,23 @@ where
     }
 }
 
-impl<CardType: From<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Hand<CardType> {
-    fn parse(input: &str) -> Self {
-        let split = input.split_whitespace().collect::<Vec<_>>();
-        let cards = split[0];
-        let bid = split[1];
+impl<CardType: TryFrom<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Hand<CardType> {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (cards, bid) = parse::split_once(input, input, " ")?;
 
-        let cards = cards.chars().map(CardType::from).collect::<Vec<_>>();
-        let cards = cards.try_into().unwrap();
+        let cards = cards
+            .char_indices()
+            .map(|(i, c)| {
+                CardType::try_from(c).map_err(|_| ParseError::new(input, &cards[i..], "a card"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::new(input, input, "a hand of five cards"))?;
 
-        let bid = bid.parse().unwrap();
+        let bid = parse::number(input, bid)?;
 
-        Self { cards, bid }
+        Ok(Self { cards, bid })
     }
 }
 
@@ -179,9 +209,11 @@ impl PartialOrd for JokerCard {
     }
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Card {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             'A' => Card::Ace,
             'K' => Card::King,
             'Q' => Card::Queen,
@@ -195,14 +227,16 @@ impl From<char> for Card {
             '4' => Card::Four,
             '3' => Card::Three,
             '2' => Card::Two,
-            _ => panic!("Invalid card: {}", value),
-        }
+            _ => return Err(value),
+        })
     }
 }
 
-impl From<char> for JokerCard {
-    fn from(value: char) -> Self {
-        JokerCard(Card::from(value))
+impl TryFrom<char> for JokerCard {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Card::try_from(value).map(JokerCard)
     }
 }
 
@@ -222,19 +256,19 @@ pub(crate) struct Input<CardType> {
     pub(crate) hands: Vec<Hand<CardType>>,
 }
 
-impl<CardType: From<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Input<CardType> {
-    pub(crate) fn parse(input: &str) -> Self {
-        let hands = input.lines().map(Hand::parse).collect();
+impl<CardType: TryFrom<char> + std::fmt::Debug + Eq + std::hash::Hash + Copy> Input<CardType> {
+    pub(crate) fn parse(input: &str) -> Result<Self, ParseError> {
+        let hands = parse::lines(input, Hand::parse)?;
 
-        Self { hands }
+        Ok(Self { hands })
     }
 }
 
-fn part_1(sample_input: &str) -> usize {
-    let mut input = Input::<Card>::parse(sample_input);
+pub fn part1(sample_input: &str) -> Result<usize, ParseError> {
+    let mut input = Input::<Card>::parse(sample_input)?;
     input.hands.sort();
 
-    input
+    Ok(input
         .hands
         .iter()
         .enumerate()
@@ -243,14 +277,14 @@ fn part_1(sample_input: &str) -> usize {
 
             h.bid * multiplier
         })
-        .sum()
+        .sum())
 }
 
-fn part_2(sample_input: &str) -> usize {
-    let mut input = Input::<JokerCard>::parse(sample_input);
+pub fn part2(sample_input: &str) -> Result<usize, ParseError> {
+    let mut input = Input::<JokerCard>::parse(sample_input)?;
     input.hands.sort();
 
-    input
+    Ok(input
         .hands
         .iter()
         .enumerate()
@@ -259,23 +293,7 @@ fn part_2(sample_input: &str) -> usize {
 
             h.bid * multiplier
         })
-        .sum()
-}
-
-fn main() {
-    let sample_input = include_str!("../example.txt");
-    let sample_part_1_ans = part_1(sample_input);
-    dbg!(sample_part_1_ans);
-
-    let my_input = include_str!("../input1.txt");
-    let my_part_1_ans = part_1(my_input);
-    dbg!(my_part_1_ans);
-
-    let sample_part_2_ans = part_2(sample_input);
-    dbg!(sample_part_2_ans);
-
-    let my_part_2_ans = part_2(my_input);
-    dbg!(my_part_2_ans);
+        .sum())
 }
 
 #[cfg(test)]
@@ -284,21 +302,48 @@ mod test {
 
     #[test]
     fn test_four_of_kinds() {
-        let a = Hand::<Card>::parse("33332 1");
-        let b = Hand::<Card>::parse("2AAAA 2");
+        let a = Hand::<Card>::parse("33332 1").unwrap();
+        let b = Hand::<Card>::parse("2AAAA 2").unwrap();
 
         assert!(a > b);
         assert_eq!(a.hand_type(), HandType::FourOfAKind);
         assert_eq!(b.hand_type(), HandType::FourOfAKind);
     }
 
+    #[test]
+    fn test_example() {
+        let sample_input = include_str!("../example.txt");
+
+        assert_eq!(part1(sample_input), Ok(6440));
+        assert_eq!(part2(sample_input), Ok(5905));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = part1("32T3K 765\nT55X5 684\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "a card");
+
+        let err = part1("32T3K 765\nKK67 28\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a hand of five cards, found `KK67`"
+        );
+
+        let err = part1("32T3K\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: expected ` `, found end of line"
+        );
+    }
+
     #[test]
     fn test_full_house() {
-        let a = Hand::<Card>::parse("77888 1");
-        let b = Hand::<Card>::parse("77788 2");
+        let a = Hand::<Card>::parse("77888 1").unwrap();
+        let b = Hand::<Card>::parse("77788 2").unwrap();
 
         assert!(a > b);
         assert_eq!(a.hand_type(), HandType::FullHouse);
         assert_eq!(b.hand_type(), HandType::FullHouse);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{math::crt, parse, Answer, Day, ParseError, Solution};
+
+pub const DAY: Day = Day {
+    number: 8,
+    title: "Haunted Wasteland",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day8,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<fn(&str) -> Result<Answer, ParseError>> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(parse)
+    }
+}
+
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+/// The instructions and the left and right node reachable from each node.
+fn parse(input: &str) -> Result<(&str, Network<'_>), ParseError> {
+    let (instructions, remaining) = parse::split_once(input, input, "\n")?;
+    if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+        return Err(ParseError::new(input, &instructions[i..], "`L` or `R`"));
+    }
+
+    let mut movements = HashMap::new();
+    for line in remaining.lines().filter(|line| !line.is_empty()) {
+        let (key, value_pair) = parse::split_once(input, line, " = ")?;
+        let value_pair = parse::strip_prefix(input, value_pair, "(")?;
+        let (left, right) = parse::split_once(input, value_pair, ", ")?;
+        let (right, _) = parse::split_once(input, right, ")")?;
+        movements.insert(key, (left, right));
+    }
+
+    // Report the first unknown node in the input.
+    let unknown = movements
+        .values()
+        .flat_map(|&(left, right)| [left, right])
+        .filter(|node| !movements.contains_key(node))
+        .min_by_key(|node| node.as_ptr());
+    if let Some(node) = unknown {
+        return Err(ParseError::new(input, node, "a known node"));
+    }
+    Ok((instructions, movements))
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    let (instructions, movements) = parse(input)?;
+
+    let mut current = "AAA";
+    if !movements.contains_key(current) {
+        return Err(ParseError::end(input, "a node named `AAA`"));
+    }
+
+    let mut steps = 0;
+    'outer: loop {
+        for c in instructions.chars() {
+            let (left, right) = movements[current];
+            if c == 'L' {
+                current = left;
+            } else {
+                current = right;
+            }
+            steps += 1;
+            if current == "ZZZ" {
+                break 'outer;
+            }
+        }
+    }
+    Ok(steps)
+}
+
+/// The step at which a ghost starting at `start` first reaches a node ending
+/// in `Z`, and how many steps it takes to get back to one. Assumes the ghost's
+/// loop passes a single such node, so it keeps arriving with that period.
+/// `None` if the ghost stops reaching `Z` nodes, which shows as it coming
+/// back to the same node at the same instruction without passing one.
+fn z_cycle(start: &str, instructions: &str, movements: &Network) -> Option<(u64, u64)> {
+    let mut current = start;
+    let mut first = None;
+    let mut seen = HashSet::new();
+    let instructions = instructions.chars().enumerate().cycle();
+    for (step, (i, instruction)) in (1..).zip(instructions) {
+        if !seen.insert((current, i)) {
+            return None;
+        }
+        let (left, right) = movements[current];
+        current = if instruction == 'L' { left } else { right };
+        if current.ends_with('Z') {
+            match first {
+                None => first = Some(step),
+                Some(first) => return Some((first, step - first)),
+            }
+            seen.clear();
+        }
+    }
+    unreachable!("the instructions repeat forever")
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let (instructions, movements) = parse(input)?;
+
+    let cycles: Vec<(u64, u64)> = movements
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|start| {
+            z_cycle(start, instructions, &movements).ok_or_else(|| {
+                ParseError::new(input, start, "a ghost that keeps reaching `Z` nodes")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    // Every ghost is on a `Z` node at steps `first + k * period`; find the
+    // first step that is one for all of them.
+    let Some((step, period)) = crt(cycles.iter().copied()) else {
+        return Err(ParseError::end(
+            input,
+            "ghosts that are on `Z` nodes together within a u64 of steps",
+        ));
+    };
+    let earliest = cycles.iter().map(|&(first, _)| first).max().unwrap_or(0);
+    let periods_to_wait = earliest.saturating_sub(step).div_ceil(period);
+    Ok(step + periods_to_wait * period)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ghosts_never_meet() {
+        // One ghost is on `11Z` at odd steps, the other on `22Z` at even ones.
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n\
+                     22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n";
+        let err = part2(input).unwrap_err();
+        assert!(err.expected.starts_with("ghosts that are on `Z` nodes"));
+        assert_eq!(
+            part2("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap(),
+            1
+        );
+
+        // A ghost that never reaches a `Z` node, and one that only does once.
+        let err = part2("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        let input = "LR\n\n11A = (11Z, 11B)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n";
+        assert_eq!(
+            part2(input).unwrap_err().expected,
+            "a ghost that keeps reaching `Z` nodes"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::{parse, Answer, Day, ParseError, Part, Solution};
+
+pub const DAY: Day = Day {
+    number: 9,
+    title: "Mirage Maintenance",
+    input_path: concat!(env!("CARGO_MANIFEST_DIR"), "/input1.txt"),
+    solution: &Day9,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(&self) -> Option<Part> {
+        Some(|input| Ok(part2(input)?.into()))
+    }
+
+    fn parser(&self) -> Option<fn(&str)> {
+        aoc_core::parser!(|input| parse::lines(input, parse_history))
+    }
+}
+
+/// A line of readings. Fewer than two leave nothing to extrapolate from.
+fn parse_history(line: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = parse::number_list(line, line)?;
+    if numbers.len() < 2 {
+        return Err(ParseError::new(line, line, "at least two numbers"));
+    }
+    Ok(numbers)
+}
+
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    let mut sum = 0;
+    for line_numbers in parse::lines(input, parse_history)? {
+        let mut history = Vec::new();
+        history.push(line_numbers.clone());
+        let mut differences = diffs(&line_numbers);
+        history.push(differences.clone());
+        while differences.iter().any(|x| *x != 0) {
+            differences = diffs(&differences);
+            history.push(differences.clone());
+        }
+        history.reverse();
+
+        let mut prediction = 0i64;
+        for x in history.windows(2) {
+            let second = &x[1];
+            prediction += second.last().unwrap();
+        }
+        sum += prediction;
+    }
+    Ok(sum)
+}
+
+pub fn part2(input: &str) -> Result<i64, ParseError> {
+    let mut sum = 0;
+    for line_numbers in parse::lines(input, parse_history)? {
+        let mut history = Vec::new();
+        history.push(line_numbers.clone());
+        let mut differences = diffs(&line_numbers);
+        history.push(differences.clone());
+        while differences.iter().any(|x| *x != 0) {
+            differences = diffs(&differences);
+            history.push(differences.clone());
+        }
+        history.reverse();
+        let mut prediction = 0i64;
+        for x in history.windows(2) {
+            let second = &x[1];
+            prediction = second.first().unwrap() - prediction;
+        }
+        sum += prediction;
+    }
+    Ok(sum)
+}
+
+fn diffs(numbers: &[i64]) -> Vec<i64> {
+    let mut differences = Vec::new();
+    for numbers in numbers.windows(2) {
+        let a = &numbers[0];
+        let b = &numbers[1];
+        differences.push(b - a);
+    }
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_histories() {
+        let err = part1("0 3 6\n\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "at least two numbers")
+        );
+        let err = part2("0 3 6\n7\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(part1("0 3 6 9 12 15\n").unwrap(), 18);
+    }
+}